@@ -0,0 +1,21 @@
+use std::env;
+
+fn main() {
+  println!("cargo::rustc-check-cfg=cfg(native_runtime)");
+  println!("cargo::rustc-check-cfg=cfg(native_testing)");
+
+  // React is only available on targets where wasm-bindgen can import JS. On
+  // these targets, the native runtime features have no effect, so enabling
+  // them through feature unification can't change the JS-backed API.
+  let family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+  let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+  let has_js = family.split(',').any(|x| x == "wasm") && os != "wasi";
+
+  if !has_js && env::var_os("CARGO_FEATURE_NATIVE_RUNTIME").is_some() {
+    println!("cargo::rustc-cfg=native_runtime");
+
+    if env::var_os("CARGO_FEATURE_TESTING").is_some() {
+      println!("cargo::rustc-cfg=native_testing");
+    }
+  }
+}
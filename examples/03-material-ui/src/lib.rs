@@ -20,7 +20,7 @@ macro_rules! impl_mui_component {
     $(
       impl HMuiComponentExt for H<$Component> {
         fn sx(self, style: &Style) -> Self {
-          self.attr("sx", style.as_ref())
+          self.attr("sx", &style.clone().into())
         }
       }
     )*
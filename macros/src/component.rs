@@ -0,0 +1,479 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+  parse::{Parse, ParseStream},
+  punctuated::Punctuated,
+  spanned::Spanned,
+  Attribute, Error, FnArg, GenericArgument, Ident, ItemFn, Pat, PathArguments,
+  Result, ReturnType, Token, Type,
+};
+
+/// Arguments of the `#[component]` attribute, e.g.
+/// `#[component(derive(PartialEq))]`.
+#[derive(Default)]
+pub struct ComponentArgs {
+  derives: Vec<syn::Path>,
+}
+
+impl Parse for ComponentArgs {
+  fn parse(input: ParseStream) -> Result<Self> {
+    let mut result = ComponentArgs::default();
+
+    while !input.is_empty() {
+      let ident: Ident = input.parse()?;
+
+      if ident != "derive" {
+        return Err(Error::new(
+          ident.span(),
+          "unknown argument, expected `derive(…)`",
+        ));
+      }
+
+      let content;
+      syn::parenthesized!(content in input);
+      result.derives.extend(
+        Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?,
+      );
+
+      if !input.is_empty() {
+        input.parse::<Token![,]>()?;
+      }
+    }
+
+    Ok(result)
+  }
+}
+
+struct Prop {
+  attrs: Vec<Attribute>,
+  mutability: Option<Token![mut]>,
+  ident: Ident,
+  /// The type as written in the function signature
+  arg_ty: Type,
+  /// The type of the field in the props struct
+  field_ty: Type,
+  /// Whether the argument is passed by reference
+  by_ref: bool,
+  /// The inner type if the argument is an `Option<T>`
+  optional: Option<Type>,
+}
+
+fn option_inner(ty: &Type) -> Option<Type> {
+  let Type::Path(path) = ty else { return None };
+  let segment = path.path.segments.last()?;
+
+  if segment.ident != "Option" || path.qself.is_some() {
+    return None;
+  }
+
+  match &segment.arguments {
+    PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+      match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+fn field_type(arg_ty: &Type) -> Result<(Type, bool)> {
+  match arg_ty {
+    Type::Reference(reference) => {
+      if let Some(mutability) = reference.mutability {
+        return Err(Error::new(
+          mutability.span(),
+          "components cannot take mutable references as props",
+        ));
+      }
+
+      let elem = &reference.elem;
+
+      // Unsized types need to be stored behind a reference-counted pointer
+      let field_ty = match &**elem {
+        Type::Path(path) if path.path.is_ident("str") => {
+          syn::parse_quote!(::std::rc::Rc<#elem>)
+        }
+        Type::Slice(_) | Type::TraitObject(_) => {
+          syn::parse_quote!(::std::rc::Rc<#elem>)
+        }
+        _ => (**elem).clone(),
+      };
+
+      Ok((field_ty, true))
+    }
+    _ => Ok((arg_ty.clone(), false)),
+  }
+}
+
+fn parse_prop(arg: &FnArg) -> Result<Prop> {
+  let FnArg::Typed(arg) = arg else {
+    return Err(Error::new(
+      arg.span(),
+      "components cannot take a `self` parameter",
+    ));
+  };
+
+  let Pat::Ident(pat) = &*arg.pat else {
+    return Err(Error::new(
+      arg.pat.span(),
+      "component props need to be simple identifiers",
+    ));
+  };
+
+  if let Some(by_ref) = &pat.by_ref {
+    return Err(Error::new(
+      by_ref.span(),
+      "`ref` bindings are not supported",
+    ));
+  }
+
+  // `key` and `ref` are handled by React and never passed to components
+  if pat.ident == "key" || pat.ident == "r#ref" {
+    return Err(Error::new(
+      pat.ident.span(),
+      format!(
+        "`{}` is reserved by React and cannot be used as prop name",
+        pat.ident.to_string().trim_start_matches("r#")
+      ),
+    ));
+  }
+
+  // Setters are inherent methods, so they would shadow the methods of
+  // `Component` or collide with the generated constructor
+  if ["build", "memoized", "new", "render"]
+    .iter()
+    .any(|name| pat.ident == name)
+  {
+    return Err(Error::new(
+      pat.ident.span(),
+      format!(
+        "`{}` is the name of a component method and cannot be used as prop name",
+        pat.ident
+      ),
+    ));
+  }
+
+  let (field_ty, by_ref) = field_type(&arg.ty)?;
+
+  Ok(Prop {
+    attrs: arg.attrs.clone(),
+    mutability: pat.mutability,
+    ident: pat.ident.clone(),
+    arg_ty: (*arg.ty).clone(),
+    optional: (!by_ref).then(|| option_inner(&field_ty)).flatten(),
+    field_ty,
+    by_ref,
+  })
+}
+
+pub fn component(args: ComponentArgs, item: ItemFn) -> Result<TokenStream> {
+  let ItemFn {
+    attrs,
+    vis,
+    sig,
+    block,
+  } = item;
+
+  if !sig.generics.params.is_empty() || sig.generics.where_clause.is_some() {
+    return Err(Error::new(
+      sig.generics.span(),
+      "components cannot be generic",
+    ));
+  }
+
+  if sig.asyncness.is_some() || sig.constness.is_some() {
+    return Err(Error::new(
+      sig.fn_token.span(),
+      "components need to be plain functions",
+    ));
+  }
+
+  match &sig.output {
+    ReturnType::Type(_, ty)
+      if matches!(
+        &**ty,
+        Type::Path(path) if path.path.segments.last()
+          .map(|segment| segment.ident == "VNode")
+          .unwrap_or(false)
+      ) => {}
+    output => {
+      return Err(Error::new(
+        output.span(),
+        "components need to return a `VNode`",
+      ))
+    }
+  }
+
+  let name = &sig.ident;
+  let name_str = name.to_string();
+  let props = sig
+    .inputs
+    .iter()
+    .map(parse_prop)
+    .collect::<Result<Vec<_>>>()?;
+
+  let (doc_attrs, other_attrs): (Vec<_>, Vec<_>) = attrs
+    .into_iter()
+    .partition(|attr| attr.path().is_ident("doc"));
+  let derives = &args.derives;
+  let derive_attr = (!derives.is_empty()).then(|| {
+    quote! { #[derive( #( #derives ),* )] }
+  });
+
+  let fields = props.iter().map(|prop| {
+    let Prop {
+      attrs,
+      ident,
+      field_ty,
+      ..
+    } = prop;
+
+    quote! {
+      #( #attrs )*
+      #vis #ident: #field_ty
+    }
+  });
+
+  let new_params =
+    props
+      .iter()
+      .filter(|prop| prop.optional.is_none())
+      .map(|prop| {
+        let Prop {
+          ident, field_ty, ..
+        } = prop;
+
+        quote! { #ident: impl ::std::convert::Into<#field_ty> }
+      });
+  let new_fields = props.iter().map(|prop| {
+    let ident = &prop.ident;
+
+    match prop.optional {
+      Some(_) => quote! { #ident: ::std::option::Option::None },
+      None => quote! { #ident: ::std::convert::Into::into(#ident) },
+    }
+  });
+
+  let setters = props.iter().filter_map(|prop| {
+    let Prop {
+      ident, optional, ..
+    } = prop;
+    let inner = optional.as_ref()?;
+    let doc = format!("Sets the optional prop `{ident}`.");
+
+    Some(quote! {
+      #[doc = #doc]
+      #vis fn #ident(mut self, value: impl ::std::convert::Into<#inner>) -> Self {
+        self.#ident = ::std::option::Option::Some(
+          ::std::convert::Into::into(value)
+        );
+        self
+      }
+    })
+  });
+
+  // The statements of the function body are spliced into `render()` instead of
+  // its block, so no `unused_braces` warning is triggered
+  let stmts = &block.stmts;
+  let bindings = props.iter().map(|prop| {
+    let Prop {
+      mutability,
+      ident,
+      arg_ty,
+      by_ref,
+      ..
+    } = prop;
+
+    if *by_ref {
+      quote! { let #mutability #ident: #arg_ty = &self.#ident; }
+    } else {
+      quote! {
+        let #mutability #ident: #arg_ty =
+          ::std::clone::Clone::clone(&self.#ident);
+      }
+    }
+  });
+
+  // A builder used by `html!` which sets props by name, checks for missing
+  // required props at compile time, and builds the component through `new()`
+  // and the setters of the optional props.
+  let builder = format_ident!("__{}Builder", name);
+  let required = props
+    .iter()
+    .filter(|prop| prop.optional.is_none())
+    .collect::<Vec<_>>();
+  let type_params = (0..required.len())
+    .map(|i| format_ident!("__T{}", i))
+    .collect::<Vec<_>>();
+  let unset = required.iter().map(|_| quote! { () }).collect::<Vec<_>>();
+  let required_tys = required.iter().map(|prop| &prop.field_ty);
+  let required_idents = required.iter().map(|prop| &prop.ident);
+  let optional_idents = props
+    .iter()
+    .filter(|prop| prop.optional.is_some())
+    .map(|prop| &prop.ident)
+    .collect::<Vec<_>>();
+
+  let builder_fields = props.iter().map(|prop| {
+    let ident = &prop.ident;
+
+    match &prop.optional {
+      Some(inner) => quote! { #ident: ::std::option::Option<#inner> },
+      None => {
+        let index = required
+          .iter()
+          .position(|required| required.ident == *ident)
+          .unwrap();
+        let type_param = &type_params[index];
+
+        quote! { #ident: #type_param }
+      }
+    }
+  });
+
+  let builder_init = props.iter().map(|prop| {
+    let ident = &prop.ident;
+
+    match prop.optional {
+      Some(_) => quote! { #ident: ::std::option::Option::None },
+      None => quote! { #ident: () },
+    }
+  });
+
+  let builder_setters = props.iter().map(|prop| {
+    let ident = &prop.ident;
+
+    match &prop.optional {
+      Some(inner) => quote! {
+        impl< #( #type_params ),* > #builder< #( #type_params ),* > {
+          #vis fn #ident(
+            mut self,
+            value: impl ::std::convert::Into<#inner>,
+          ) -> Self {
+            self.#ident = ::std::option::Option::Some(
+              ::std::convert::Into::into(value)
+            );
+            self
+          }
+        }
+      },
+      None => {
+        let index = required
+          .iter()
+          .position(|required| required.ident == *ident)
+          .unwrap();
+        let field_ty = &prop.field_ty;
+        let params = type_params
+          .iter()
+          .enumerate()
+          .filter(|(i, _)| *i != index)
+          .map(|(_, param)| param);
+        let input_params = type_params.iter().enumerate().map(|(i, param)| {
+          if i == index {
+            quote! { () }
+          } else {
+            quote! { #param }
+          }
+        });
+        let output_params = type_params.iter().enumerate().map(|(i, param)| {
+          if i == index {
+            quote! { #field_ty }
+          } else {
+            quote! { #param }
+          }
+        });
+        let fields = props.iter().map(|other| {
+          let other = &other.ident;
+
+          if other == ident {
+            quote! { #other: ::std::convert::Into::into(value) }
+          } else {
+            quote! { #other: self.#other }
+          }
+        });
+
+        quote! {
+          impl< #( #params ),* > #builder< #( #input_params ),* > {
+            #vis fn #ident(
+              self,
+              value: impl ::std::convert::Into<#field_ty>,
+            ) -> #builder< #( #output_params ),* > {
+              #builder {
+                #( #fields, )*
+              }
+            }
+          }
+        }
+      }
+    }
+  });
+
+  let new_doc = format!(
+    "Creates a new `{name_str}` component with the given required props."
+  );
+
+  Ok(quote! {
+    #( #doc_attrs )*
+    #derive_attr
+    #vis struct #name {
+      #( #fields, )*
+    }
+
+    impl #name {
+      #[doc = #new_doc]
+      #[allow(clippy::new_without_default)]
+      #vis fn new( #( #new_params ),* ) -> Self {
+        Self {
+          #( #new_fields, )*
+        }
+      }
+
+      #( #setters )*
+
+      #[doc(hidden)]
+      #[allow(dead_code)]
+      #vis fn __builder() -> #builder< #( #unset ),* > {
+        #builder {
+          #( #builder_init, )*
+        }
+      }
+    }
+
+    #[doc(hidden)]
+    #[allow(dead_code)]
+    #vis struct #builder< #( #type_params ),* > {
+      #( #builder_fields, )*
+    }
+
+    #( #builder_setters )*
+
+    #[allow(dead_code)]
+    impl #builder< #( #required_tys ),* > {
+      #vis fn __build(self) -> #name {
+        #[allow(unused_mut)]
+        let mut component = #name::new( #( self.#required_idents ),* );
+
+        #(
+          if let ::std::option::Option::Some(value) = self.#optional_idents {
+            component = component.#optional_idents(value);
+          }
+        )*
+
+        component
+      }
+    }
+
+    impl ::wasm_react::Component for #name {
+      #( #other_attrs )*
+      fn render(&self) -> ::wasm_react::VNode {
+        #( #bindings )*
+        #( #stmts )*
+      }
+
+      fn _build_params(&self) -> ::wasm_react::BuildParams {
+        ::wasm_react::BuildParams::new(#name_str)
+      }
+    }
+  })
+}
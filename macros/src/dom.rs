@@ -0,0 +1,388 @@
+// The DOM attributes and events of `H<HtmlTag>`. `wasm-react` generates the
+// corresponding methods from these lists with `dom_attrs!` and `dom_events!`,
+// and `html!` uses them to map attribute names to methods.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, Type};
+
+/// `(React prop name, method name, parameter type)` of DOM attributes.
+pub const DOM_ATTRS: &[(&str, &str, &str)] = &[
+  // Standard HTML Attributes
+  ("accessKey", "accesskey", "&str"),
+  ("contentEditable", "contenteditable", "bool"),
+  ("contextMenu", "contextmenu", "&str"),
+  ("dir", "dir", "&str"),
+  ("draggable", "draggable", "bool"),
+  ("hidden", "hidden", "bool"),
+  ("id", "id", "&str"),
+  ("lang", "lang", "&str"),
+  ("placeholder", "placeholder", "&str"),
+  ("slot", "slot", "&str"),
+  ("spellCheck", "spellcheck", "bool"),
+  ("tabIndex", "tabindex", "i32"),
+  ("title", "title", "&str"),
+  ("translate", "translate", "&str"),
+  ("radioGroup", "radiogroup", "&str"),
+  // WAI-ARIA
+  ("role", "role", "&str"),
+  // RDFa Attributes
+  ("about", "about", "&str"),
+  ("datatype", "datatype", "&str"),
+  ("inlist", "inlist", "impl Into<PropValue>"),
+  ("prefix", "prefix", "&str"),
+  ("property", "property", "&str"),
+  ("resource", "resource", "&str"),
+  ("vocab", "vocab", "&str"),
+  // Living Standard
+  ("inputMode", "inputmode", "&str"),
+  ("is", "is", "&str"),
+  // Standard HTML Attributes
+  ("accept", "accept", "&str"),
+  ("acceptCharset", "acceptcharset", "&str"),
+  ("action", "action", "&str"),
+  ("allowFullScreen", "allowfullscreen", "bool"),
+  ("allowTransparency", "allowtransparency", "bool"),
+  ("alt", "alt", "&str"),
+  ("autoComplete", "autocomplete", "&str"),
+  ("autoFocus", "autofocus", "bool"),
+  ("autoPlay", "autoplay", "bool"),
+  ("capture", "capture", "impl Into<PropValue>"),
+  ("cellPadding", "cellpadding", "impl Into<PropValue>"),
+  ("cellSpacing", "cellspacing", "impl Into<PropValue>"),
+  ("challenge", "challenge", "&str"),
+  ("charSet", "charset", "&str"),
+  ("checked", "checked", "bool"),
+  ("cite", "cite", "&str"),
+  ("classID", "classid", "&str"),
+  ("cols", "cols", "u32"),
+  ("colSpan", "colspan", "u32"),
+  ("content", "content", "&str"),
+  ("controls", "controls", "bool"),
+  ("coords", "coords", "&str"),
+  ("crossOrigin", "crossorigin", "&str"),
+  ("data", "data", "&str"),
+  ("dateTime", "datetime", "&str"),
+  ("default", "default", "bool"),
+  ("defer", "defer", "bool"),
+  ("disabled", "disabled", "bool"),
+  ("download", "download", "impl Into<PropValue>"),
+  ("encType", "enctype", "&str"),
+  ("form", "form", "&str"),
+  ("formAction", "formaction", "&str"),
+  ("formEncType", "formenctype", "&str"),
+  ("formMethod", "formmethod", "&str"),
+  ("formNoValidate", "formnovalidate", "bool"),
+  ("formTarget", "formtarget", "&str"),
+  ("frameBorder", "frameborder", "impl Into<PropValue>"),
+  ("headers", "headers", "&str"),
+  ("height", "height", "impl Into<PropValue>"),
+  ("high", "high", "f64"),
+  ("href", "href", "&str"),
+  ("hrefLang", "hreflang", "&str"),
+  ("htmlFor", "html_for", "&str"),
+  ("type", "html_type", "&str"),
+  ("httpEquiv", "httpequiv", "&str"),
+  ("integrity", "integrity", "&str"),
+  ("keyParams", "keyparams", "&str"),
+  ("keyType", "keytype", "&str"),
+  ("kind", "kind", "&str"),
+  ("label", "label", "&str"),
+  ("list", "list", "&str"),
+  ("low", "low", "f64"),
+  ("manifest", "manifest", "&str"),
+  ("marginHeight", "marginheight", "f64"),
+  ("marginWidth", "marginwidth", "f64"),
+  ("max", "max", "f64"),
+  ("maxLength", "maxlength", "f64"),
+  ("media", "media", "&str"),
+  ("mediaGroup", "mediagroup", "&str"),
+  ("method", "method", "&str"),
+  ("min", "min", "impl Into<PropValue>"),
+  ("minLength", "minlength", "f64"),
+  ("multiple", "multiple", "bool"),
+  ("muted", "muted", "bool"),
+  ("name", "name", "&str"),
+  ("nonce", "nonce", "&str"),
+  ("noValidate", "novalidate", "bool"),
+  ("open", "open", "bool"),
+  ("optimum", "optimum", "f64"),
+  ("pattern", "pattern", "&str"),
+  ("playsInline", "playsinline", "bool"),
+  ("poster", "poster", "&str"),
+  ("preload", "preload", "&str"),
+  ("readOnly", "readonly", "bool"),
+  ("rel", "rel", "&str"),
+  ("required", "required", "bool"),
+  ("reversed", "reversed", "bool"),
+  ("rows", "rows", "u32"),
+  ("rowSpan", "rowspan", "u32"),
+  ("sandbox", "sandbox", "&str"),
+  ("scope", "scope", "&str"),
+  ("scoped", "scoped", "bool"),
+  ("scrolling", "scrolling", "&str"),
+  ("seamless", "seamless", "bool"),
+  ("selected", "selected", "bool"),
+  ("shape", "shape", "&str"),
+  ("size", "size", "f64"),
+  ("sizes", "sizes", "&str"),
+  ("span", "span", "u32"),
+  ("src", "src", "&str"),
+  ("srcDoc", "srcdoc", "&str"),
+  ("srcLang", "srclang", "&str"),
+  ("srcSet", "srcset", "&str"),
+  ("start", "start", "f64"),
+  ("step", "step", "impl Into<PropValue>"),
+  ("summary", "summary", "&str"),
+  ("target", "target", "&str"),
+  ("useMap", "usemap", "&str"),
+  ("value", "value", "impl Into<PropValue>"),
+  ("width", "width", "impl Into<PropValue>"),
+  ("wmode", "wmode", "&str"),
+  ("wrap", "wrap", "&str"),
+];
+
+/// `(React prop name, method name, event type)` of DOM events.
+pub const DOM_EVENTS: &[(&str, &str, &str)] = &[
+  ("onFocus", "on_focus", "FocusEvent"),
+  ("onFocusCapture", "on_focus_capture", "FocusEvent"),
+  ("onBlur", "on_blur", "FocusEvent"),
+  ("onBlurCapture", "on_blur_capture", "FocusEvent"),
+  ("onChange", "on_change", "Event"),
+  ("onChangeCapture", "on_change_capture", "Event"),
+  ("onBeforeInput", "on_beforeinput", "Event"),
+  ("onBeforeInputCapture", "on_beforeinput_capture", "Event"),
+  ("onInput", "on_input", "Event"),
+  ("onInputCapture", "on_input_capture", "Event"),
+  ("onReset", "on_reset", "Event"),
+  ("onResetCapture", "on_reset_capture", "Event"),
+  ("onSubmit", "on_submit", "Event"),
+  ("onSubmitCapture", "on_submit_capture", "Event"),
+  ("onInvalid", "on_invalid", "Event"),
+  ("onInvalidCapture", "on_invalid_capture", "Event"),
+  ("onSelect", "on_select", "UiEvent"),
+  ("onSelectCapture", "on_select_capture", "UiEvent"),
+  ("onLoad", "on_load", "Event"),
+  ("onLoadCapture", "on_load_capture", "Event"),
+  ("onKeyDown", "on_keydown", "KeyboardEvent"),
+  ("onKeyDownCapture", "on_keydown_capture", "KeyboardEvent"),
+  ("onKeyPress", "on_keypress", "KeyboardEvent"),
+  ("onKeyPressCapture", "on_keypress_capture", "KeyboardEvent"),
+  ("onKeyUp", "on_keyup", "KeyboardEvent"),
+  ("onKeyUpCapture", "on_keyup_capture", "KeyboardEvent"),
+  ("onAbort", "on_abort", "Event"),
+  ("onAbortCapture", "on_abort_capture", "Event"),
+  ("onCanPlay", "on_canplay", "Event"),
+  ("onCanPlayCapture", "on_canplay_capture", "Event"),
+  ("onCanPlayThrough", "on_canplay_through", "Event"),
+  (
+    "onCanPlayThroughCapture",
+    "on_canplay_through_capture",
+    "Event",
+  ),
+  ("onDurationChange", "on_duration_change", "Event"),
+  (
+    "onDurationChangeCapture",
+    "on_duration_change_capture",
+    "Event",
+  ),
+  ("onEmptied", "on_emptied", "Event"),
+  ("onEmptiedCapture", "on_emptied_capture", "Event"),
+  ("onEncrypted", "on_encrypted", "Event"),
+  ("onEncryptedCapture", "on_encrypted_capture", "Event"),
+  ("onEnded", "on_ended", "Event"),
+  ("onEndedCapture", "on_ended_capture", "Event"),
+  ("onLoadedData", "on_loadeddata", "Event"),
+  ("onLoadedDataCapture", "on_loadeddata_capture", "Event"),
+  ("onLoadedMetadata", "on_loadedmetadata", "Event"),
+  (
+    "onLoadedMetadataCapture",
+    "on_loadedmetadata_capture",
+    "Event",
+  ),
+  ("onLoadStart", "on_loadstart", "Event"),
+  ("onLoadStartCapture", "on_loadstart_capture", "Event"),
+  ("onPause", "on_pause", "Event"),
+  ("onPauseCapture", "on_pause_capture", "Event"),
+  ("onPlay", "on_play", "Event"),
+  ("onPlayCapture", "on_play_capture", "Event"),
+  ("onPlaying", "on_playing", "Event"),
+  ("onPlayingCapture", "on_playing_capture", "Event"),
+  ("onProgress", "on_progress", "Event"),
+  ("onProgressCapture", "on_progress_capture", "Event"),
+  ("onRateChange", "on_ratechange", "Event"),
+  ("onRateChangeCapture", "on_ratechange_capture", "Event"),
+  ("onSeeked", "on_seeked", "Event"),
+  ("onSeekedCapture", "on_seeked_capture", "Event"),
+  ("onSeeking", "on_seeking", "Event"),
+  ("onSeekingCapture", "on_seeking_capture", "Event"),
+  ("onStalled", "on_stalled", "Event"),
+  ("onStalledCapture", "on_stalled_capture", "Event"),
+  ("onSuspend", "on_suspend", "Event"),
+  ("onSuspendCapture", "on_suspend_capture", "Event"),
+  ("onTimeUpdate", "on_timeupdate", "Event"),
+  ("onTimeUpdateCapture", "on_timeupdate_capture", "Event"),
+  ("onVolumeChange", "on_volumechange", "Event"),
+  ("onVolumeChangeCapture", "on_volumechange_capture", "Event"),
+  ("onWaiting", "on_waiting", "Event"),
+  ("onWaitingCapture", "on_waiting_capture", "Event"),
+  ("onAuxClick", "on_auxclick", "MouseEvent"),
+  ("onAuxClickCapture", "on_auxclick_capture", "MouseEvent"),
+  ("onClick", "on_click", "MouseEvent"),
+  ("onClickCapture", "on_click_capture", "MouseEvent"),
+  ("onContextMenu", "on_context_menu", "MouseEvent"),
+  (
+    "onContextMenuCapture",
+    "on_context_menu_capture",
+    "MouseEvent",
+  ),
+  ("onDoubleClick", "on_doubleclick", "MouseEvent"),
+  (
+    "onDoubleClickCapture",
+    "on_doubleclick_capture",
+    "MouseEvent",
+  ),
+  ("onMouseDown", "on_mousedown", "MouseEvent"),
+  ("onMouseDownCapture", "on_mousedown_capture", "MouseEvent"),
+  ("onMouseEnter", "on_mouseenter", "MouseEvent"),
+  ("onMouseLeave", "on_mouseleave", "MouseEvent"),
+  ("onMouseMove", "on_mousemove", "MouseEvent"),
+  ("onMouseMoveCapture", "on_mousemove_capture", "MouseEvent"),
+  ("onMouseOut", "on_mouseout", "MouseEvent"),
+  ("onMouseOutCapture", "on_mouseout_capture", "MouseEvent"),
+  ("onMouseOver", "on_mouseover", "MouseEvent"),
+  ("onMouseOverCapture", "on_mouseover_capture", "MouseEvent"),
+  ("onMouseUp", "on_mouseup", "MouseEvent"),
+  ("onMouseUpCapture", "on_mouseup_capture", "MouseEvent"),
+  ("onPointerDown", "on_pointerdown", "PointerEvent"),
+  (
+    "onPointerDownCapture",
+    "on_pointerdown_capture",
+    "PointerEvent",
+  ),
+  ("onPointerMove", "on_pointermove", "PointerEvent"),
+  (
+    "onPointerMoveCapture",
+    "on_pointermove_capture",
+    "PointerEvent",
+  ),
+  ("onPointerUp", "on_pointerup", "PointerEvent"),
+  ("onPointerUpCapture", "on_pointerup_capture", "PointerEvent"),
+  ("onPointerCancel", "on_pointercancel", "PointerEvent"),
+  (
+    "onPointerCancelCapture",
+    "on_pointercancel_capture",
+    "PointerEvent",
+  ),
+  ("onPointerEnter", "on_pointerenter", "PointerEvent"),
+  (
+    "onPointerEnterCapture",
+    "on_pointerenter_capture",
+    "PointerEvent",
+  ),
+  ("onPointerLeave", "on_pointerleave", "PointerEvent"),
+  (
+    "onPointerLeaveCapture",
+    "on_pointerleave_capture",
+    "PointerEvent",
+  ),
+  ("onPointerOver", "on_pointerover", "PointerEvent"),
+  (
+    "onPointerOverCapture",
+    "on_pointerover_capture",
+    "PointerEvent",
+  ),
+  ("onPointerOut", "on_pointerout", "PointerEvent"),
+  (
+    "onPointerOutCapture",
+    "on_pointerout_capture",
+    "PointerEvent",
+  ),
+  (
+    "onGotPointerCapture",
+    "on_gotpointer_capture",
+    "PointerEvent",
+  ),
+  (
+    "onGotPointerCaptureCapture",
+    "on_gotpointer_capture_capture",
+    "PointerEvent",
+  ),
+  (
+    "onLostPointerCapture",
+    "on_lostpointer_capture",
+    "PointerEvent",
+  ),
+  (
+    "onLostPointerCaptureCapture",
+    "on_lostpointer_capture_capture",
+    "PointerEvent",
+  ),
+  ("onDrag", "on_drag", "DragEvent"),
+  ("onDragCapture", "on_dragcapture", "DragEvent"),
+  ("onDragEnd", "on_dragend", "DragEvent"),
+  ("onDragEndCapture", "on_dragend_capture", "DragEvent"),
+  ("onDragEnter", "on_dragenter", "DragEvent"),
+  ("onDragEnterCapture", "on_dragenter_capture", "DragEvent"),
+  ("onDragExit", "on_dragexit", "DragEvent"),
+  ("onDragExitCapture", "on_dragexit_capture", "DragEvent"),
+  ("onDragLeave", "on_dragleave", "DragEvent"),
+  ("onDragLeaveCapture", "on_dragleave_capture", "DragEvent"),
+  ("onDragOver", "on_dragover", "DragEvent"),
+  ("onDragOverCapture", "on_dragover_capture", "DragEvent"),
+  ("onDragStart", "on_dragstart", "DragEvent"),
+  ("onDragStartCapture", "on_dragstart_capture", "DragEvent"),
+  ("onDrop", "on_drop", "DragEvent"),
+  ("onDropCapture", "on_drop_capture", "DragEvent"),
+  ("onScroll", "on_scroll", "UiEvent"),
+  ("onScrollCapture", "on_scroll_capture", "UiEvent"),
+  ("onWheel", "on_wheel", "WheelEvent"),
+  ("onWheelCapture", "on_wheel_capture", "WheelEvent"),
+  ("onAnimationStart", "on_animationstart", "AnimationEvent"),
+  (
+    "onAnimationStartCapture",
+    "on_animationstart_capture",
+    "AnimationEvent",
+  ),
+  ("onAnimationEnd", "on_animationend", "AnimationEvent"),
+  (
+    "onAnimationEndCapture",
+    "on_animationend_capture",
+    "AnimationEvent",
+  ),
+  (
+    "onAnimationIteration",
+    "on_animationiteration",
+    "AnimationEvent",
+  ),
+  (
+    "onAnimationIterationCapture",
+    "on_animationiteration_capture",
+    "AnimationEvent",
+  ),
+  ("onTransitionEnd", "on_transition_end", "TransitionEvent"),
+  (
+    "onTransitionEndCapture",
+    "on_transition_end_capture",
+    "TransitionEvent",
+  ),
+];
+
+fn invoke(callback: Ident, list: &[(&str, &str, &str)]) -> TokenStream {
+  let items = list.iter().map(|(name, method, ty)| {
+    let method = Ident::new(method, Span::call_site());
+    let ty = syn::parse_str::<Type>(ty).expect("invalid type in DOM list");
+
+    quote! { #method, #name => #ty; }
+  });
+
+  quote! { #callback! { #( #items )* } }
+}
+
+pub fn dom_attrs(callback: Ident) -> TokenStream {
+  invoke(callback, DOM_ATTRS)
+}
+
+pub fn dom_events(callback: Ident) -> TokenStream {
+  invoke(callback, DOM_EVENTS)
+}
@@ -0,0 +1,521 @@
+use crate::dom::{DOM_ATTRS, DOM_EVENTS};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+  braced,
+  ext::IdentExt,
+  parse::{Parse, ParseStream},
+  spanned::Spanned,
+  token::Brace,
+  Error, Ident, Lit, LitStr, Path, Result, Token,
+};
+
+/// The markup accepted by `html!`, i.e. a list of nodes.
+pub struct Markup(Vec<Node>);
+
+enum Node {
+  Element(Element),
+  Fragment(Vec<Node>),
+  Block(TokenStream),
+  Text(LitStr),
+}
+
+enum Tag {
+  /// An HTML element, e.g. `<div>` or `<web-component>`
+  Html(String, Span),
+  /// A Rust component, e.g. `<Counter>`
+  Rust(Path),
+  /// A JS component imported with `import_components!`, e.g. `<js:MyComponent>`
+  Js(Path),
+}
+
+struct Element {
+  tag: Tag,
+  attrs: Vec<Attr>,
+  children: Vec<Node>,
+}
+
+enum AttrName {
+  /// Written as an identifier, possibly with dashes, e.g. `aria-label`
+  Ident(String, Span),
+  /// Written as a string literal, e.g. `"onCustomEvent"`
+  Literal(LitStr),
+}
+
+struct Attr {
+  name: AttrName,
+  value: Option<TokenStream>,
+}
+
+fn parse_dashed_name(input: ParseStream) -> Result<(String, Span)> {
+  let first = Ident::parse_any(input)?;
+  let span = first.span();
+  let mut name = first.to_string();
+
+  while input.peek(Token![-]) {
+    input.parse::<Token![-]>()?;
+    name.push('-');
+    name.push_str(&Ident::parse_any(input)?.to_string());
+  }
+
+  Ok((name, span))
+}
+
+impl Tag {
+  fn name(&self) -> String {
+    match self {
+      Tag::Html(name, _) => name.clone(),
+      Tag::Rust(path) => path.to_token_stream().to_string(),
+      Tag::Js(path) => format!("js:{}", path.to_token_stream()),
+    }
+  }
+
+  fn span(&self) -> Span {
+    match self {
+      Tag::Html(_, span) => *span,
+      Tag::Rust(path) | Tag::Js(path) => path.span(),
+    }
+  }
+}
+
+impl Parse for Tag {
+  fn parse(input: ParseStream) -> Result<Self> {
+    if input.peek(Ident::peek_any)
+      && input.peek2(Token![:])
+      && !input.peek2(Token![::])
+    {
+      let prefix = Ident::parse_any(input)?;
+
+      if prefix != "js" {
+        return Err(Error::new(
+          prefix.span(),
+          "unknown tag prefix, expected `js:`",
+        ));
+      }
+
+      input.parse::<Token![:]>()?;
+      return Ok(Tag::Js(Path::parse_mod_style(input)?));
+    }
+
+    let fork = input.fork();
+    let (name, span) = parse_dashed_name(&fork)?;
+    let is_html = name.contains('-')
+      || (!fork.peek(Token![::])
+        && name.starts_with(|c: char| c.is_ascii_lowercase()));
+
+    if is_html {
+      parse_dashed_name(input)?;
+      Ok(Tag::Html(name, span))
+    } else {
+      Ok(Tag::Rust(Path::parse_mod_style(input)?))
+    }
+  }
+}
+
+impl Parse for Attr {
+  fn parse(input: ParseStream) -> Result<Self> {
+    let name = if input.peek(LitStr) {
+      AttrName::Literal(input.parse()?)
+    } else {
+      let (name, span) = parse_dashed_name(input)?;
+      AttrName::Ident(name, span)
+    };
+
+    let value = if input.peek(Token![=]) {
+      input.parse::<Token![=]>()?;
+
+      Some(if input.peek(Brace) {
+        let content;
+        braced!(content in input);
+        let content = content.parse::<TokenStream>()?;
+
+        quote! { { #content } }
+      } else {
+        input.parse::<Lit>()?.into_token_stream()
+      })
+    } else {
+      None
+    };
+
+    Ok(Attr { name, value })
+  }
+}
+
+fn parse_children(input: ParseStream) -> Result<Vec<Node>> {
+  let mut children = vec![];
+
+  while !(input.is_empty() || input.peek(Token![<]) && input.peek2(Token![/])) {
+    children.push(input.parse()?);
+  }
+
+  Ok(children)
+}
+
+impl Parse for Node {
+  fn parse(input: ParseStream) -> Result<Self> {
+    if input.peek(LitStr) {
+      return Ok(Node::Text(input.parse()?));
+    }
+
+    if input.peek(Brace) {
+      let content;
+      let brace = braced!(content in input);
+      let content = content.parse::<TokenStream>()?;
+
+      return Ok(Node::Block(
+        quote_spanned!(brace.span.join()=> { #content }),
+      ));
+    }
+
+    let lt = input.parse::<Token![<]>().map_err(|err| {
+      Error::new(
+        err.span(),
+        "expected an element, a string literal, or a block",
+      )
+    })?;
+
+    if input.peek(Token![>]) {
+      // Fragment
+      input.parse::<Token![>]>()?;
+      let children = parse_children(input)?;
+
+      input.parse::<Token![<]>().map_err(|_| {
+        Error::new(lt.span, "this fragment has not been closed")
+      })?;
+      input.parse::<Token![/]>()?;
+      input.parse::<Token![>]>()?;
+
+      return Ok(Node::Fragment(children));
+    }
+
+    let tag = input.parse::<Tag>()?;
+    let mut attrs = vec![];
+
+    while !(input.peek(Token![>]) || input.peek(Token![/])) {
+      if input.is_empty() {
+        return Err(Error::new(
+          tag.span(),
+          format!("unexpected end of input, `<{}>` is not closed", tag.name()),
+        ));
+      }
+
+      attrs.push(input.parse()?);
+    }
+
+    if input.peek(Token![/]) {
+      // Self-closing element
+      input.parse::<Token![/]>()?;
+      input.parse::<Token![>]>()?;
+
+      return Ok(Node::Element(Element {
+        tag,
+        attrs,
+        children: vec![],
+      }));
+    }
+
+    input.parse::<Token![>]>()?;
+    let children = parse_children(input)?;
+
+    if input.is_empty() {
+      return Err(Error::new(
+        tag.span(),
+        format!("`<{}>` has not been closed", tag.name()),
+      ));
+    }
+
+    input.parse::<Token![<]>()?;
+    input.parse::<Token![/]>()?;
+    let closing_tag = input.parse::<Tag>()?;
+    input.parse::<Token![>]>()?;
+
+    if closing_tag.name() != tag.name() {
+      return Err(Error::new(
+        closing_tag.span(),
+        format!("mismatched closing tag, expected `</{}>`", tag.name()),
+      ));
+    }
+
+    Ok(Node::Element(Element {
+      tag,
+      attrs,
+      children,
+    }))
+  }
+}
+
+impl Parse for Markup {
+  fn parse(input: ParseStream) -> Result<Self> {
+    let mut nodes = vec![];
+
+    while !input.is_empty() {
+      nodes.push(input.parse()?);
+    }
+
+    Ok(Markup(nodes))
+  }
+}
+
+/// Returns the Levenshtein distance between the given ASCII-lowercased
+/// strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let a = a.to_ascii_lowercase().into_bytes();
+  let b = b.to_ascii_lowercase().into_bytes();
+  let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+  for i in 1..=a.len() {
+    let mut prev = row[0];
+    row[0] = i;
+
+    for j in 1..=b.len() {
+      let current = row[j];
+
+      row[j] = (row[j] + 1)
+        .min(row[j - 1] + 1)
+        .min(prev + usize::from(a[i - 1] != b[j - 1]));
+      prev = current;
+    }
+  }
+
+  row[b.len()]
+}
+
+fn known_attr(name: &str) -> Option<(&'static str, bool)> {
+  // `className` and `style` are implemented by hand in `wasm-react`
+  let name = match name {
+    "class" | "className" => return Some(("class_name", true)),
+    "style" => return Some(("style", true)),
+    "for" => "htmlFor",
+    name => name,
+  };
+
+  DOM_ATTRS
+    .iter()
+    .find(|(attr, _, _)| *attr == name)
+    .map(|(_, method, ty)| (*method, ty.starts_with('&')))
+    .or_else(|| {
+      DOM_EVENTS
+        .iter()
+        .find(|(event, _, _)| *event == name)
+        .map(|(_, method, _)| (*method, true))
+    })
+}
+
+/// Rejects unknown attributes which look like a typo of a known attribute or
+/// event, including HTML spellings like `tabindex` instead of `tabIndex`.
+fn check_typo(name: &str, span: Span) -> Result<()> {
+  if name.starts_with("data-") || name.starts_with("aria-") {
+    return Ok(());
+  }
+
+  // Short names are more likely to be similar by chance
+  let max_distance = if name.len() <= 4 { 1 } else { 2 };
+  let attrs = ["class", "className", "style", "for"]
+    .into_iter()
+    .chain(DOM_ATTRS.iter().map(|(attr, _, _)| *attr))
+    .map(|attr| ("attribute", attr));
+  let events = DOM_EVENTS.iter().map(|(event, _, _)| ("event", *event));
+  let suggestion = attrs
+    .chain(events)
+    .map(|(kind, known)| (kind, known, edit_distance(name, known)))
+    .filter(|(_, _, distance)| *distance <= max_distance)
+    .min_by_key(|(_, _, distance)| *distance);
+
+  match suggestion {
+    Some((kind, known, _)) => Err(Error::new(
+      span,
+      format!(
+        "unknown {kind} `{name}`, did you mean `{known}`? \
+        Use a string literal `\"{name}\"` to set it anyway"
+      ),
+    )),
+    None => Ok(()),
+  }
+}
+
+fn attr_value(attr: &Attr) -> TokenStream {
+  attr.value.clone().unwrap_or_else(|| quote! { true })
+}
+
+fn children_to_tokens(children: &[Node]) -> TokenStream {
+  match children {
+    [] => quote! { () },
+    [child] => child.to_token_stream(),
+    children => {
+      let list = Ident::new("children", Span::mixed_site());
+
+      quote! {
+        {
+          let mut #list = ::wasm_react::VNode::new();
+          #( #list.push(&::std::convert::Into::into(#children)); )*
+          #list
+        }
+      }
+    }
+  }
+}
+
+impl Element {
+  fn html_to_tokens(&self, name: &str) -> Result<TokenStream> {
+    let mut methods = vec![];
+
+    for attr in &self.attrs {
+      let value = attr_value(attr);
+
+      methods.push(match &attr.name {
+        AttrName::Literal(name) => {
+          quote! { .attr(#name, &::std::convert::Into::into(#value)) }
+        }
+        AttrName::Ident(name, _) if name == "key" => {
+          quote! { .key(::std::option::Option::Some(#value)) }
+        }
+        AttrName::Ident(name, _) if name == "ref" => {
+          quote! { .ref_container_typed(&#value) }
+        }
+        AttrName::Ident(name, span) => match known_attr(name) {
+          Some((method, by_ref)) => {
+            let method = Ident::new(method, *span);
+
+            if by_ref {
+              quote! { .#method(&#value) }
+            } else {
+              quote! { .#method(#value) }
+            }
+          }
+          None => {
+            check_typo(name, *span)?;
+
+            quote! { .attr(#name, &::std::convert::Into::into(#value)) }
+          }
+        },
+      });
+    }
+
+    let children = children_to_tokens(&self.children);
+
+    // Custom elements can only be passed as string literal
+    let tag = match syn::parse_str::<Ident>(name) {
+      Ok(ident) => ident.into_token_stream(),
+      Err(_) => LitStr::new(name, Span::call_site()).into_token_stream(),
+    };
+
+    Ok(quote! {
+      ::wasm_react::props::H::new(
+        ::wasm_react::props::HtmlTag::<
+          ::wasm_react::html_element_type!(#tag)
+        >::new(#name)
+      )
+        #( #methods )*
+        .build(#children)
+    })
+  }
+
+  fn rust_to_tokens(&self, path: &Path) -> Result<TokenStream> {
+    let mut setters = vec![];
+    let mut key = None;
+
+    for attr in &self.attrs {
+      let value = attr_value(attr);
+
+      match &attr.name {
+        AttrName::Ident(name, _) if name == "key" => key = Some(value),
+        AttrName::Ident(name, span) if !name.contains('-') && name != "ref" => {
+          let setter = Ident::new(name, *span);
+          setters.push(quote! { .#setter(#value) });
+        }
+        AttrName::Ident(name, span) => {
+          return Err(Error::new(
+            *span,
+            format!("Rust components do not support the attribute `{name}`"),
+          ))
+        }
+        AttrName::Literal(name) => {
+          return Err(Error::new(
+            name.span(),
+            "Rust components only support identifiers as attribute names",
+          ))
+        }
+      }
+    }
+
+    if !self.children.is_empty() {
+      let children = children_to_tokens(&self.children);
+      setters.push(quote! { .children(#children) });
+    }
+
+    // The builder is generated by `#[component]`
+    let component = quote! { #path::__builder() #( #setters )* .__build() };
+
+    Ok(match key {
+      Some(key) => quote! {
+        ::wasm_react::Component::build(
+          ::wasm_react::Component::key(
+            #component,
+            ::std::option::Option::Some(#key),
+          )
+        )
+      },
+      None => quote! { ::wasm_react::Component::build(#component) },
+    })
+  }
+
+  fn js_to_tokens(&self, path: &Path) -> Result<TokenStream> {
+    let methods = self.attrs.iter().map(|attr| {
+      let value = attr_value(attr);
+
+      match &attr.name {
+        AttrName::Ident(name, _) if name == "key" => {
+          quote! { .key(::std::option::Option::Some(#value)) }
+        }
+        AttrName::Ident(name, _) if name == "ref" => {
+          quote! { .ref_container_typed(&#value) }
+        }
+        AttrName::Ident(name, _) => {
+          quote! { .attr(#name, &::std::convert::Into::into(#value)) }
+        }
+        AttrName::Literal(name) => {
+          quote! { .attr(#name, &::std::convert::Into::into(#value)) }
+        }
+      }
+    });
+
+    let children = children_to_tokens(&self.children);
+
+    Ok(quote! {
+      #path::new()
+        #( #methods )*
+        .build(#children)
+    })
+  }
+
+  fn try_to_tokens(&self) -> Result<TokenStream> {
+    match &self.tag {
+      Tag::Html(name, _) => self.html_to_tokens(name),
+      Tag::Rust(path) => self.rust_to_tokens(path),
+      Tag::Js(path) => self.js_to_tokens(path),
+    }
+  }
+}
+
+impl ToTokens for Node {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    tokens.extend(match self {
+      Node::Element(element) => element
+        .try_to_tokens()
+        .unwrap_or_else(|err| err.to_compile_error()),
+      Node::Fragment(children) => {
+        let children = children_to_tokens(children);
+        quote! { ::wasm_react::VNode::from(#children) }
+      }
+      Node::Block(block) => block.clone(),
+      Node::Text(text) => text.to_token_stream(),
+    });
+  }
+}
+
+pub fn html(markup: Markup) -> TokenStream {
+  let nodes = markup.0;
+  let result = children_to_tokens(&nodes);
+
+  quote! { ::wasm_react::VNode::from(#result) }
+}
@@ -0,0 +1,56 @@
+//! Procedural macros for `wasm-react`. Use the re-exports in `wasm-react`
+//! instead of depending on this crate directly.
+
+mod component;
+mod dom;
+mod html;
+mod typescript;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, Ident, ItemFn};
+
+#[proc_macro_attribute]
+pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
+  let args = parse_macro_input!(attr as component::ComponentArgs);
+  let item = parse_macro_input!(item as ItemFn);
+
+  component::component(args, item)
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+#[proc_macro]
+pub fn html(input: TokenStream) -> TokenStream {
+  let markup = parse_macro_input!(input as html::Markup);
+
+  html::html(markup).into()
+}
+
+/// Invokes the given macro with the list of DOM attributes, e.g.
+/// `accesskey, "accessKey" => &str;`. Used by `wasm-react` internally.
+#[doc(hidden)]
+#[proc_macro]
+pub fn dom_attrs(input: TokenStream) -> TokenStream {
+  let callback = parse_macro_input!(input as Ident);
+
+  dom::dom_attrs(callback).into()
+}
+
+/// Invokes the given macro with the list of DOM events, e.g.
+/// `on_click, "onClick" => MouseEvent;`. Used by `wasm-react` internally.
+#[doc(hidden)]
+#[proc_macro]
+pub fn dom_events(input: TokenStream) -> TokenStream {
+  let callback = parse_macro_input!(input as Ident);
+
+  dom::dom_events(callback).into()
+}
+
+#[proc_macro_derive(TypeScriptProps, attributes(ts))]
+pub fn typescript_props(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  typescript::typescript_props(input)
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
@@ -0,0 +1,334 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+  parse::ParseStream, spanned::Spanned, Attribute, Data, DeriveInput, Error,
+  Expr, Fields, GenericArgument, LitStr, PathArguments, Result, Token, Type,
+};
+
+/// Converts a snake case field name according to a serde `rename_all` rule.
+fn rename(name: &str, rule: &str) -> Option<String> {
+  let words = name.split('_').filter(|word| !word.is_empty());
+  let capitalize = |word: &str| {
+    let mut chars = word.chars();
+
+    chars
+      .next()
+      .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+      .unwrap_or_default()
+  };
+
+  Some(match rule {
+    "lowercase" => name.to_lowercase(),
+    "UPPERCASE" => name.to_uppercase(),
+    "snake_case" => name.to_string(),
+    "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+    "kebab-case" => name.replace('_', "-"),
+    "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+    "PascalCase" => words.map(capitalize).collect(),
+    "camelCase" => {
+      let pascal = words.map(capitalize).collect::<String>();
+      let mut chars = pascal.chars();
+
+      chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+    }
+    _ => return None,
+  })
+}
+
+/// Skips the value of an unknown attribute argument.
+fn skip_meta_value(input: ParseStream) -> Result<()> {
+  if input.peek(Token![=]) {
+    input.parse::<Token![=]>()?;
+    input.parse::<Expr>()?;
+  } else if input.peek(syn::token::Paren) {
+    let content;
+    syn::parenthesized!(content in input);
+    content.parse::<TokenStream>()?;
+  }
+
+  Ok(())
+}
+
+#[derive(Default)]
+struct ContainerAttrs {
+  rename_all: Option<LitStr>,
+  default: bool,
+}
+
+impl ContainerAttrs {
+  fn parse(attrs: &[Attribute]) -> Result<Self> {
+    let mut result = Self::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("rename_all") {
+          result.rename_all = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("default") {
+          result.default = true;
+          skip_meta_value(meta.input)?;
+        } else {
+          skip_meta_value(meta.input)?;
+        }
+
+        Ok(())
+      })?;
+    }
+
+    Ok(result)
+  }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+  rename: Option<String>,
+  ty: Option<String>,
+  skip: bool,
+  default: bool,
+}
+
+impl FieldAttrs {
+  fn parse(attrs: &[Attribute]) -> Result<Self> {
+    let mut result = Self::default();
+
+    for attr in attrs {
+      if attr.path().is_ident("serde") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("rename") {
+            result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+          } else if meta.path.is_ident("skip")
+            || meta.path.is_ident("skip_deserializing")
+          {
+            result.skip = true;
+          } else if meta.path.is_ident("default") {
+            result.default = true;
+            skip_meta_value(meta.input)?;
+          } else {
+            skip_meta_value(meta.input)?;
+          }
+
+          Ok(())
+        })?;
+      } else if attr.path().is_ident("ts") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("type") {
+            result.ty = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+          } else if meta.path.is_ident("optional") {
+            result.default = true;
+            Ok(())
+          } else {
+            Err(meta.error("unknown argument, expected `type` or `optional`"))
+          }
+        })?;
+      }
+    }
+
+    Ok(result)
+  }
+}
+
+fn generic_types(args: &PathArguments) -> Vec<&Type> {
+  match args {
+    PathArguments::AngleBracketed(args) => args
+      .args
+      .iter()
+      .filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+      })
+      .collect(),
+    _ => vec![],
+  }
+}
+
+fn is_void(ty: &Type) -> bool {
+  match ty {
+    Type::Tuple(tuple) => tuple.elems.is_empty(),
+    Type::Path(path) => path
+      .path
+      .segments
+      .last()
+      .map(|segment| segment.ident == "Void")
+      .unwrap_or(false),
+    _ => false,
+  }
+}
+
+/// Returns the inner type if the given type is an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+  let Type::Path(path) = ty else { return None };
+  let segment = path.path.segments.last()?;
+
+  match generic_types(&segment.arguments)[..] {
+    [inner] if segment.ident == "Option" => Some(inner),
+    _ => None,
+  }
+}
+
+/// Maps a Rust type to the TypeScript type of the corresponding JS value.
+/// Unknown types are mapped to `any`.
+fn ts_type(ty: &Type) -> String {
+  match ty {
+    Type::Reference(reference) => ts_type(&reference.elem),
+    Type::Paren(paren) => ts_type(&paren.elem),
+    Type::Group(group) => ts_type(&group.elem),
+    Type::Slice(slice) => format!("{}[]", ts_type(&slice.elem)),
+    Type::Array(array) => format!("{}[]", ts_type(&array.elem)),
+    Type::Tuple(tuple) if tuple.elems.is_empty() => "void".into(),
+    Type::Tuple(tuple) => format!(
+      "[{}]",
+      tuple
+        .elems
+        .iter()
+        .map(ts_type)
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+    Type::Path(path) if path.qself.is_none() => {
+      let Some(segment) = path.path.segments.last() else {
+        return "any".into();
+      };
+      let ident = segment.ident.to_string();
+      let args = generic_types(&segment.arguments);
+
+      match (ident.as_str(), &args[..]) {
+        (
+          "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64"
+          | "usize" | "f32" | "f64" | "Number",
+          [],
+        ) => "number".into(),
+        ("bool" | "Boolean", []) => "boolean".into(),
+        ("String" | "str" | "char" | "JsString", []) => "string".into(),
+        ("Void", []) => "void".into(),
+        ("Function", []) => "Function".into(),
+        ("Object", []) => "object".into(),
+        ("Array", []) => "any[]".into(),
+        ("Date", []) => "Date".into(),
+        ("Promise", []) => "Promise<any>".into(),
+        ("Option", [inner]) => format!("{} | undefined", ts_type(inner)),
+        ("Box" | "Rc" | "Arc" | "Cow" | "RefCell" | "Cell", [inner]) => {
+          ts_type(inner)
+        }
+        ("Vec" | "VecDeque" | "HashSet" | "BTreeSet", [inner]) => {
+          format!("{}[]", ts_type(inner))
+        }
+        ("HashMap" | "BTreeMap", [key, value]) => {
+          format!("Record<{}, {}>", ts_type(key), ts_type(value))
+        }
+        ("Callback", [arg, ret @ ..]) => {
+          let params = if is_void(arg) {
+            String::new()
+          } else {
+            format!("arg: {}", ts_type(arg))
+          };
+          let ret = ret.first().map(|ret| ts_type(ret));
+
+          format!("({params}) => {}", ret.as_deref().unwrap_or("void"))
+        }
+        // DOM types are available globally in TypeScript
+        (ident, [])
+          if ident.ends_with("Event")
+            || ident.ends_with("Element")
+            || ident == "Node" =>
+        {
+          ident.to_string()
+        }
+        _ => "any".into(),
+      }
+    }
+    _ => "any".into(),
+  }
+}
+
+pub fn typescript_props(input: DeriveInput) -> Result<TokenStream> {
+  let name = &input.ident;
+
+  if !input.generics.params.is_empty() {
+    return Err(Error::new(
+      input.generics.span(),
+      "cannot generate TypeScript declarations for generic types",
+    ));
+  }
+
+  let Data::Struct(data) = &input.data else {
+    return Err(Error::new(
+      name.span(),
+      "TypeScript declarations can only be generated for structs",
+    ));
+  };
+
+  let container_attrs = ContainerAttrs::parse(&input.attrs)?;
+  let rename_all = container_attrs
+    .rename_all
+    .as_ref()
+    .map(|lit| {
+      let rule = lit.value();
+
+      rename("", &rule)
+        .map(|_| rule)
+        .ok_or_else(|| Error::new(lit.span(), "unknown `rename_all` rule"))
+    })
+    .transpose()?;
+
+  let mut members = vec![];
+
+  match &data.fields {
+    Fields::Named(fields) => {
+      for field in &fields.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+
+        if attrs.skip {
+          continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let field_name = attrs.rename.unwrap_or_else(|| {
+          let field_name = ident.to_string();
+          let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name);
+
+          match &rename_all {
+            Some(rule) => rename(field_name, rule).unwrap_or_default(),
+            None => field_name.to_string(),
+          }
+        });
+        let inner = option_inner(&field.ty);
+        let optional =
+          inner.is_some() || attrs.default || container_attrs.default;
+        let ty = attrs
+          .ty
+          .unwrap_or_else(|| ts_type(inner.unwrap_or(&field.ty)));
+
+        members.push(format!(
+          "  {field_name:?}{}: {ty};\n",
+          if optional { "?" } else { "" }
+        ));
+      }
+    }
+    Fields::Unit => {}
+    Fields::Unnamed(fields) => {
+      return Err(Error::new(
+        fields.span(),
+        "TypeScript declarations can only be generated for structs with \
+        named fields",
+      ))
+    }
+  }
+
+  let declaration = format!(
+    "export interface {name}Props {{\n{}}}\n\n\
+    interface __WasmReactPropsRegistry {{\n  {:?}: {name}Props;\n}}",
+    members.concat(),
+    name.to_string()
+  );
+  let const_ident =
+    format_ident!("__WASMREACT_TYPESCRIPT_{}", name.to_string().to_uppercase());
+
+  Ok(quote! {
+    #[::wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+    #[allow(non_upper_case_globals)]
+    const #const_ident: &'static str = #declaration;
+  })
+}
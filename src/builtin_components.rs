@@ -1,9 +1,13 @@
+#[cfg(native_runtime)]
+use crate::ssr::ElementType;
 use crate::{
+  panic_hook::{install_panic_message_hook, take_last_panic_message},
   props::{HType, H},
-  react_bindings, VNode,
+  react_bindings, Callback, VNode, Void,
 };
+use js_sys::{Array, Function, Reflect, WebAssembly::RuntimeError};
 use std::borrow::Cow;
-use wasm_bindgen::JsValue;
+use wasm_bindgen::{intern, JsCast, JsValue, UnwrapThrowExt};
 
 /// A component that specifies the loading indicator when loading lazy descendant
 /// components.
@@ -39,6 +43,11 @@ impl HType for Suspense {
   fn as_js(&self) -> Cow<'_, JsValue> {
     Cow::Borrowed(&react_bindings::SUSPENSE)
   }
+
+  #[cfg(native_runtime)]
+  fn element_type(&self) -> ElementType<'_> {
+    ElementType::Suspense
+  }
 }
 
 impl Suspense {
@@ -51,6 +60,187 @@ impl Suspense {
 impl H<Suspense> {
   /// Sets a fallback when loading lazy descendant components.
   pub fn fallback(self, children: impl Into<VNode>) -> Self {
-    self.attr("fallback", children.into().as_ref())
+    self.attr("fallback", &children.into().into())
+  }
+}
+
+/// Returns the panic message belonging to the given caught error if the error
+/// has been caused by a Rust panic.
+fn panic_message_of(error: &JsValue) -> Option<String> {
+  if !error.is_instance_of::<RuntimeError>() {
+    return None;
+  }
+
+  // Attach the panic message to the error object, so it can still be retrieved
+  // when the fallback rerenders.
+  let key = JsValue::from(intern("__wasmReactPanicMessage"));
+  let attached = Reflect::get(error, &key).unwrap_or(JsValue::UNDEFINED);
+
+  if attached.is_undefined() {
+    let message = take_last_panic_message();
+
+    Reflect::set(
+      error,
+      &key,
+      &message
+        .as_deref()
+        .map(JsValue::from)
+        .unwrap_or(JsValue::NULL),
+    )
+    .expect_throw("cannot write into error object");
+
+    message
+  } else {
+    attached.as_string()
+  }
+}
+
+/// Contains information about an error caught by an [`ErrorBoundary`].
+#[derive(Debug, Clone)]
+pub struct CaughtError {
+  error: JsValue,
+  panic_message: Option<String>,
+  component_stack: Option<String>,
+  reset: Option<Callback<Void>>,
+}
+
+impl CaughtError {
+  /// Returns the thrown JS value.
+  pub fn error(&self) -> &JsValue {
+    &self.error
+  }
+
+  /// Returns the panic message if the error has been caused by a Rust panic.
+  pub fn panic_message(&self) -> Option<&str> {
+    self.panic_message.as_deref()
+  }
+
+  /// Returns the React component stack of the error, i.e. the components
+  /// from the one that has thrown up to the error boundary.
+  ///
+  /// The component stack is not available yet when the fallback is rendered
+  /// for the first time, in which case `None` is returned.
+  pub fn component_stack(&self) -> Option<&str> {
+    self.component_stack.as_deref()
+  }
+
+  /// Returns a callback that resets the error boundary and tries to render its
+  /// children again.
+  ///
+  /// Returns `None` if the error has been caused by a Rust panic. A panic
+  /// aborts the WebAssembly instance without unwinding, so destructors haven't
+  /// run and borrows might not have been released. Rendering Rust components
+  /// again after a panic is unsound, so only JS errors can be recovered from.
+  pub fn reset(&self) -> Option<&Callback<Void>> {
+    self.reset.as_ref()
+  }
+}
+
+impl From<Array> for CaughtError {
+  fn from(value: Array) -> Self {
+    let error = value.get(0);
+    let reset = value
+      .get(1)
+      .dyn_into::<Function>()
+      .expect_throw("unable to read reset function from error boundary");
+
+    let panic_message = panic_message_of(&error);
+
+    CaughtError {
+      reset: panic_message.is_none().then(|| {
+        Callback::new(move |_: Void| {
+          // Let failed resources of the subtree be fetched again
+          #[cfg(feature = "futures")]
+          crate::hooks::evict_failed_resources();
+
+          reset
+            .call0(&JsValue::NULL)
+            .expect_throw("unable to call reset function");
+        })
+      }),
+      panic_message,
+      component_stack: value.get(2).as_string(),
+      error,
+    }
+  }
+}
+
+/// A component that catches errors thrown while rendering its subtree and
+/// displays a fallback instead of crashing the whole app.
+///
+/// Errors thrown in event handlers, effects, or asynchronous code will not be
+/// caught. Rust panics in render functions will be caught and their messages
+/// are made available through [`CaughtError::panic_message()`], but the error
+/// boundary cannot be reset after a panic, see [`CaughtError::reset()`].
+///
+/// See [React documentation](https://react.dev/reference/react/Component#catching-rendering-errors-with-an-error-boundary).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::*;
+/// #
+/// # struct SomeComponent {}
+/// # impl Component for SomeComponent {
+/// #   fn render(&self) -> VNode { VNode::default() }
+/// # }
+/// #
+/// # fn f() -> VNode {
+/// ErrorBoundary::new()
+///   .fallback(&Callback::new(|error: CaughtError| {
+///     h!(div[."error"]).build((
+///       h!(p).build(error.panic_message().unwrap_or("Something went wrong.")),
+///       error.reset().map(|reset| {
+///         h!(button)
+///           .on_click(&reset.premap(|_| Void))
+///           .build("Try again")
+///       }),
+///     ))
+///   }))
+///   .build(
+///     SomeComponent { /* … */ }.build()
+///   )
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ErrorBoundary;
+
+impl HType for ErrorBoundary {
+  fn as_js(&self) -> Cow<'_, JsValue> {
+    Cow::Owned(react_bindings::get_error_boundary())
+  }
+
+  #[cfg(native_runtime)]
+  fn element_type(&self) -> ElementType<'_> {
+    ElementType::Transparent
+  }
+}
+
+impl ErrorBoundary {
+  /// Creates a new error boundary component builder.
+  pub fn new() -> H<ErrorBoundary> {
+    install_panic_message_hook();
+
+    H::new(ErrorBoundary)
+  }
+}
+
+impl H<ErrorBoundary> {
+  /// Sets the fallback that will be rendered when an error has been caught.
+  pub fn fallback(self, f: &Callback<CaughtError, VNode>) -> Self {
+    #[cfg(not(native_runtime))]
+    {
+      self.attr_callback(
+        "fallback",
+        &f.premap(|args: Array| CaughtError::from(args))
+          .postmap(JsValue::from),
+      )
+    }
+    #[cfg(native_runtime)]
+    {
+      // Errors are not caught on the server
+      let _ = f;
+      self
+    }
   }
 }
@@ -3,11 +3,13 @@ use std::{
   fmt::Debug,
   rc::Rc,
 };
+use crate::VNode;
+use js_sys::{Array, Function, JsString, Object, Promise};
 use wasm_bindgen::{
   convert::{FromWasmAbi, IntoWasmAbi},
   describe::WasmDescribe,
   prelude::Closure,
-  JsValue, UnwrapThrowExt,
+  JsCast, JsValue, UnwrapThrowExt,
 };
 
 /// A zero-sized helper struct to simulate a JS-interoperable [`Callback`] with no input
@@ -51,6 +53,101 @@ impl From<Void> for JsValue {
   }
 }
 
+/// A trait for types that can be converted from the return value of a JS
+/// function. See [`Callback::from_js_function()`].
+pub trait FromJsReturn: Sized {
+  /// Tries to convert the given value, returning the value itself as error if
+  /// it has an incompatible type.
+  fn from_js_return(value: JsValue) -> Result<Self, JsValue>;
+}
+
+impl FromJsReturn for () {
+  fn from_js_return(_: JsValue) -> Result<Self, JsValue> {
+    Ok(())
+  }
+}
+
+impl FromJsReturn for Void {
+  fn from_js_return(_: JsValue) -> Result<Self, JsValue> {
+    Ok(Void)
+  }
+}
+
+impl FromJsReturn for VNode {
+  fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+    Ok(VNode::Single(value))
+  }
+}
+
+impl FromJsReturn for bool {
+  fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+    value.as_bool().ok_or(value)
+  }
+}
+
+impl FromJsReturn for String {
+  fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+    value.as_string().ok_or(value)
+  }
+}
+
+impl<T: FromJsReturn> FromJsReturn for Option<T> {
+  fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+    if value.is_null() || value.is_undefined() {
+      Ok(None)
+    } else {
+      T::from_js_return(value).map(Some)
+    }
+  }
+}
+
+macro_rules! impl_from_js_return {
+  {
+    floats: $( $F:ty ),*;
+    integers: $( $N:ty ),*;
+    casts: $( $T:ty ),* $(,)?
+  } => {
+    $(
+      impl FromJsReturn for $F {
+        fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+          value.as_f64().map(|x| x as $F).ok_or(value)
+        }
+      }
+    )*
+
+    $(
+      impl FromJsReturn for $N {
+        fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+          // Reject numbers which are not integral or out of range
+          value
+            .as_f64()
+            .filter(|x| x.fract() == 0.0)
+            .and_then(|x| <$N>::try_from(x as i128).ok())
+            .ok_or(value)
+        }
+      }
+    )*
+
+    $(
+      impl FromJsReturn for $T {
+        fn from_js_return(value: JsValue) -> Result<Self, JsValue> {
+          value.dyn_into()
+        }
+      }
+    )*
+  };
+}
+
+impl_from_js_return! {
+  floats:
+    f32, f64;
+  integers:
+    i8, i16, i32, i64, isize,
+    u8, u16, u32, u64, usize;
+  casts:
+    JsValue, Array, Function, JsString, Object, Promise,
+}
+
 /// This is a simplified, reference-counted wrapper around an [`FnMut(T) -> U`](FnMut)
 /// Rust closure that may be called from JS when `T` and `U` allow.
 ///
@@ -81,6 +178,42 @@ where
     }
   }
 
+  /// Creates a new [`Callback`] which calls the given JS function, e.g. a
+  /// callback passed as prop to an exported component.
+  ///
+  /// The argument is converted into a [`JsValue`] and the return value is
+  /// converted with [`FromJsReturn`]. Errors thrown by the JS function are
+  /// rethrown and return values of an unexpected type will throw as well.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use wasm_react::*;
+  /// # use wasm_bindgen::prelude::*;
+  /// # use js_sys::{Function, Reflect};
+  /// # fn f(props: &JsValue) -> Result<(), JsValue> {
+  /// let on_change: Callback<String, bool> = Callback::from_js_function(
+  ///   Reflect::get(props, &"onChange".into())?.dyn_into::<Function>()?,
+  /// );
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn from_js_function(f: Function) -> Self
+  where
+    T: Into<JsValue>,
+    U: FromJsReturn,
+  {
+    Self::new(move |arg: T| {
+      let result = f
+        .call1(&JsValue::UNDEFINED, &arg.into())
+        .unwrap_or_else(|err| wasm_bindgen::throw_val(err));
+
+      U::from_js_return(result)
+        .ok()
+        .expect_throw("unexpected return value of JS function")
+    })
+  }
+
   /// Returns a Rust closure from the callback.
   pub fn to_closure(&self) -> impl FnMut(T) -> U + 'static {
     let callback = self.clone();
@@ -1,12 +1,16 @@
-use crate::{react_bindings, VNode};
-use std::any::{type_name, Any};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr::Node;
+use crate::{hooks, panic_hook, props::PropValue, VNode};
 use js_sys::JsString;
+use std::any::{type_name, Any};
 use wasm_bindgen::prelude::*;
 
 /// Implemented by types which can serve as a [React key][key].
 ///
 /// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
-pub trait KeyType: Into<JsValue> {}
+pub trait KeyType: Into<PropValue> {}
 
 macro_rules! impl_key_type {
   { $( $T:ty ),* $( , )? } => {
@@ -15,16 +19,25 @@ macro_rules! impl_key_type {
 }
 
 impl_key_type! {
-  &str, String, JsString,
+  &str, String,
   f32, f64,
   i8, i16, i32, i64, i128, isize,
   u8, u16, u32, u64, u128, usize,
 }
 
+impl KeyType for JsString {}
+
 #[doc(hidden)]
 pub struct BuildParams {
-  name: &'static str,
-  key: Option<JsValue>,
+  pub(crate) name: &'static str,
+  key: Option<PropValue>,
+}
+
+impl BuildParams {
+  #[doc(hidden)]
+  pub fn new(name: &'static str) -> Self {
+    Self { name, key: None }
+  }
 }
 
 /// Implement this trait on a struct to create a component with the struct as
@@ -67,23 +80,27 @@ pub trait Component: Sized + 'static {
   #[doc(hidden)]
   /// Defines parameters for [`Component::build()`].
   fn _build_params(&self) -> BuildParams {
-    BuildParams {
-      name: type_name::<Self>(),
-      key: None,
-    }
+    BuildParams::new(type_name::<Self>())
   }
 
   #[doc(hidden)]
   fn _build_with_name_and_key(
     self,
     name: &'static str,
-    key: Option<JsValue>,
+    key: Option<PropValue>,
   ) -> VNode {
-    VNode::Single(react_bindings::create_rust_component(
-      name,
-      &key.unwrap_or(JsValue::UNDEFINED),
-      ComponentWrapper(Box::new(self)),
-    ))
+    #[cfg(not(native_runtime))]
+    {
+      VNode::Single(react_bindings::create_rust_component(
+        name,
+        &key.unwrap_or(JsValue::UNDEFINED),
+        ComponentWrapper(Box::new(self)),
+      ))
+    }
+    #[cfg(native_runtime)]
+    {
+      VNode::Native(Node::component(name, key, Box::new(self), None))
+    }
   }
 
   /// Returns a [`VNode`] to be included in a render function.
@@ -145,7 +162,7 @@ pub trait Component: Sized + 'static {
 ///
 /// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
 #[derive(Debug, PartialEq)]
-pub struct Keyed<T>(T, Option<JsValue>);
+pub struct Keyed<T>(T, Option<PropValue>);
 
 impl<T: Component> Component for Keyed<T> {
   fn render(&self) -> VNode {
@@ -164,7 +181,7 @@ impl<T: Component> Component for Keyed<T> {
   fn _build_with_name_and_key(
     self,
     name: &'static str,
-    key: Option<JsValue>,
+    key: Option<PropValue>,
   ) -> VNode {
     self.0._build_with_name_and_key(name, key)
   }
@@ -188,23 +205,48 @@ impl<T: Component + PartialEq> Component for Memoized<T> {
   fn _build_with_name_and_key(
     self,
     name: &'static str,
-    key: Option<JsValue>,
+    key: Option<PropValue>,
   ) -> VNode {
-    VNode::Single(react_bindings::create_rust_memo_component(
-      name,
-      &key.unwrap_or(JsValue::UNDEFINED),
-      MemoComponentWrapper(Box::new(self.0)),
-    ))
+    #[cfg(not(native_runtime))]
+    {
+      VNode::Single(react_bindings::create_rust_memo_component(
+        name,
+        &key.unwrap_or(JsValue::UNDEFINED),
+        MemoComponentWrapper(Box::new(self.0)),
+      ))
+    }
+    #[cfg(native_runtime)]
+    {
+      fn memo_eq<T: PartialEq + 'static>(x: &dyn Any, y: &dyn Any) -> bool {
+        x.downcast_ref::<T>()
+          .zip(y.downcast_ref::<T>())
+          .is_some_and(|(x, y)| x == y)
+      }
+
+      VNode::Native(Node::component(
+        name,
+        key,
+        Box::new(self.0),
+        Some(memo_eq::<T>),
+      ))
+    }
   }
 }
 
-trait ObjectSafeComponent {
+pub(crate) trait ObjectSafeComponent {
   fn render(&self) -> VNode;
+  fn as_any(&self) -> &dyn Any;
 }
 
 impl<T: Component> ObjectSafeComponent for T {
   fn render(&self) -> VNode {
-    Component::render(self)
+    panic_hook::with_component(self._build_params().name, || {
+      hooks::render_component(self)
+    })
+  }
+
+  fn as_any(&self) -> &dyn Any {
+    self
   }
 }
 
@@ -216,20 +258,16 @@ pub struct ComponentWrapper(Box<dyn ObjectSafeComponent>);
 impl ComponentWrapper {
   #[wasm_bindgen]
   pub fn render(&self) -> JsValue {
+    panic_hook::reset_component_stack();
     self.0.render().into()
   }
 }
 
 trait ObjectSafeMemoComponent: ObjectSafeComponent {
-  fn as_any(&self) -> &dyn Any;
   fn eq(&self, other: &dyn Any) -> bool;
 }
 
 impl<T: Component + PartialEq> ObjectSafeMemoComponent for T {
-  fn as_any(&self) -> &dyn Any {
-    self
-  }
-
   fn eq(&self, other: &dyn Any) -> bool {
     other
       .downcast_ref::<T>()
@@ -246,6 +284,7 @@ pub struct MemoComponentWrapper(Box<dyn ObjectSafeMemoComponent>);
 impl MemoComponentWrapper {
   #[wasm_bindgen]
   pub fn render(&self) -> JsValue {
+    panic_hook::reset_component_stack();
     self.0.render().into()
   }
 
@@ -254,3 +293,25 @@ impl MemoComponentWrapper {
     self.0.eq(other.0.as_any())
   }
 }
+
+/// Renders the given component for JS, used by
+/// [`export_components!`](crate::export_components!).
+#[doc(hidden)]
+pub fn render_exported<T: Component>(component: &T) -> wasm_bindgen::JsValue {
+  #[cfg(not(native_runtime))]
+  {
+    panic_hook::reset_component_stack();
+    panic_hook::with_component(component._build_params().name, || {
+      hooks::render_component(component)
+    })
+    .into()
+  }
+  #[cfg(native_runtime)]
+  {
+    let _ = component;
+    wasm_bindgen::throw_str(
+      "exported components cannot be rendered with the `native-runtime` \
+       feature",
+    )
+  }
+}
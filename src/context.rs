@@ -1,10 +1,18 @@
+#[cfg(native_runtime)]
+use crate::ssr::{self, Node};
+#[cfg(not(native_runtime))]
 use crate::{
   create_element, hooks::RefContainerValue, props::Props, react_bindings,
-  Component, VNode,
 };
+use crate::{Component, VNode};
+#[cfg(not(native_runtime))]
 use js_sys::Reflect;
+#[cfg(native_runtime)]
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::{marker::PhantomData, rc::Rc, thread::LocalKey};
-use wasm_bindgen::{intern, JsValue, UnwrapThrowExt};
+use wasm_bindgen::JsValue;
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{intern, UnwrapThrowExt};
 
 /// Represents a [React context][context] that can hold a global state.
 ///
@@ -13,19 +21,39 @@ use wasm_bindgen::{intern, JsValue, UnwrapThrowExt};
 /// [context]: https://react.dev/learn/passing-data-deeply-with-context
 #[derive(Debug)]
 pub struct Context<T> {
+  #[cfg(not(native_runtime))]
   js_context: JsValue,
+  #[cfg(native_runtime)]
+  pub(crate) id: usize,
+  #[cfg(native_runtime)]
+  pub(crate) default: Rc<T>,
   phantom: PhantomData<T>,
 }
 
 impl<T> AsRef<JsValue> for Context<T> {
   fn as_ref(&self) -> &JsValue {
-    &self.js_context
+    #[cfg(not(native_runtime))]
+    {
+      &self.js_context
+    }
+    #[cfg(native_runtime)]
+    {
+      ssr::unsupported("converting contexts into JS values")
+    }
   }
 }
 
 impl<T> From<Context<T>> for JsValue {
   fn from(value: Context<T>) -> Self {
-    value.js_context
+    #[cfg(not(native_runtime))]
+    {
+      value.js_context
+    }
+    #[cfg(native_runtime)]
+    {
+      let _ = value;
+      ssr::unsupported("converting contexts into JS values")
+    }
   }
 }
 
@@ -92,9 +120,22 @@ impl<T> From<Context<T>> for JsValue {
 /// }
 /// ```
 pub fn create_context<T: 'static>(init: Rc<T>) -> Context<T> {
-  Context {
-    js_context: react_bindings::create_context(RefContainerValue(init)),
-    phantom: PhantomData,
+  #[cfg(not(native_runtime))]
+  {
+    Context {
+      js_context: react_bindings::create_context(RefContainerValue(init)),
+      phantom: PhantomData,
+    }
+  }
+  #[cfg(native_runtime)]
+  {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    Context {
+      id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+      default: init,
+      phantom: PhantomData,
+    }
   }
 }
 
@@ -132,6 +173,7 @@ impl<T: 'static> ContextProvider<T> {
 }
 
 impl<T: 'static> Component for ContextProvider<T> {
+  #[cfg(not(native_runtime))]
   fn render(&self) -> VNode {
     self.context.with(|context| {
       create_element(
@@ -153,4 +195,16 @@ impl<T: 'static> Component for ContextProvider<T> {
       )
     })
   }
+
+  #[cfg(native_runtime)]
+  fn render(&self) -> VNode {
+    VNode::Native(Node::provider(
+      self.context.with(|context| context.id),
+      self
+        .value
+        .clone()
+        .map(|value| value as Rc<dyn std::any::Any>),
+      self.children.clone(),
+    ))
+  }
 }
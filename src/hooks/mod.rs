@@ -1,27 +1,53 @@
 //! This module contains bindings to React hooks.
 
 mod deps;
+mod rules_of_hooks;
+mod use_action_state;
+#[cfg(feature = "futures")]
+mod use_async_effect;
 mod use_callback;
 mod use_context;
+mod use_debug_value;
 mod use_deferred_value;
 mod use_effect;
+mod use_effect_event;
 mod use_id;
+mod use_imperative_handle;
 mod use_js_ref;
 mod use_memo;
+mod use_optimistic;
+mod use_reducer;
 mod use_ref;
+#[cfg(feature = "futures")]
+mod use_resource;
+mod use_signal;
 mod use_state;
+mod use_sync_external_store;
 mod use_tmp_ref;
 mod use_transition;
 
 pub use deps::*;
+pub(crate) use rules_of_hooks::*;
+pub use use_action_state::*;
+#[cfg(feature = "futures")]
+pub use use_async_effect::*;
 pub use use_callback::*;
 pub use use_context::*;
+pub use use_debug_value::*;
 pub use use_deferred_value::*;
 pub use use_effect::*;
+pub use use_effect_event::*;
 pub use use_id::*;
+pub use use_imperative_handle::*;
 pub use use_js_ref::*;
 pub use use_memo::*;
+pub use use_optimistic::*;
+pub use use_reducer::*;
 pub use use_ref::*;
+#[cfg(feature = "futures")]
+pub use use_resource::*;
+pub use use_signal::*;
 pub use use_state::*;
+pub use use_sync_external_store::*;
 pub(crate) use use_tmp_ref::*;
 pub use use_transition::*;
@@ -0,0 +1,199 @@
+//! Detects violations of the [rules of hooks][rules] in debug builds by
+//! comparing the order of hook calls with the previous render.
+//!
+//! [rules]: https://react.dev/reference/rules/rules-of-hooks
+
+use crate::{Component, VNode};
+#[cfg(feature = "debug")]
+use std::cell::Cell;
+#[cfg(debug_assertions)]
+use std::cell::RefCell;
+
+#[cfg(debug_assertions)]
+struct Frame {
+  component: &'static str,
+  previous: Option<Vec<&'static str>>,
+  hooks: Vec<&'static str>,
+  depth: usize,
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+  static CURRENT_FRAME: RefCell<Option<Frame>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "debug")]
+thread_local! {
+  /// The number of hooks which are currently running, nested in each other.
+  static HOOK_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Restores the previous frame even if rendering panics.
+#[cfg(debug_assertions)]
+struct FrameGuard(Option<Frame>);
+
+#[cfg(debug_assertions)]
+impl Drop for FrameGuard {
+  fn drop(&mut self) {
+    CURRENT_FRAME.with(|frame| *frame.borrow_mut() = self.0.take());
+  }
+}
+
+#[cfg(debug_assertions)]
+fn fail(component: &str, message: &str) -> ! {
+  let message = format!(
+    "rules of hooks violated in component `{component}`: {message}. Hooks \
+    must not be called conditionally, in loops, or in nested functions."
+  );
+
+  abort_render();
+
+  if cfg!(target_arch = "wasm32") {
+    wasm_bindgen::throw_str(&message)
+  } else {
+    panic!("{message}")
+  }
+}
+
+/// Forgets the currently rendering component. Has to be called before throwing
+/// JS exceptions out of render functions, since they won't run destructors.
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+pub(crate) fn abort_render() {
+  #[cfg(debug_assertions)]
+  CURRENT_FRAME.with(|frame| frame.borrow_mut().take());
+
+  #[cfg(feature = "debug")]
+  HOOK_DEPTH.with(|depth| depth.set(0));
+}
+
+/// Renders the given component while recording the order of its hook calls.
+pub(crate) fn render_component<T: Component>(component: &T) -> VNode {
+  #[cfg(debug_assertions)]
+  {
+    // Hook order of the previous render, persisted with a hook that is called
+    // before any hook of the component
+    let mut previous = super::use_ref(None::<Vec<&'static str>>);
+    let component_name = component._build_params().name;
+
+    let _guard = FrameGuard(CURRENT_FRAME.with(|frame| {
+      frame.borrow_mut().replace(Frame {
+        component: component_name,
+        previous: previous.current_mut().take(),
+        hooks: Vec::new(),
+        depth: 0,
+      })
+    }));
+
+    let result = component.render();
+
+    let frame = CURRENT_FRAME.with(|frame| frame.borrow_mut().take());
+
+    if let Some(frame) = frame {
+      if let Some(expected) = frame
+        .previous
+        .as_ref()
+        .and_then(|previous| previous.get(frame.hooks.len()))
+      {
+        fail(
+          frame.component,
+          &format!(
+            "rendered fewer hooks than during the previous render, expected \
+            hook #{} to be `{expected}`",
+            frame.hooks.len() + 1,
+          ),
+        );
+      }
+
+      previous.set_current(Some(frame.hooks));
+    }
+
+    result
+  }
+  #[cfg(not(debug_assertions))]
+  {
+    component.render()
+  }
+}
+
+/// Returned by [`check_hook()`] to ignore hooks called by the hook itself.
+pub(crate) struct HookGuard;
+
+impl HookGuard {
+  /// Returns whether the hook has been called by a component directly and not
+  /// by another hook.
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  pub(crate) fn is_outermost(&self) -> bool {
+    HOOK_DEPTH.with(|depth| depth.get() == 1)
+  }
+}
+
+#[cfg(any(debug_assertions, feature = "debug"))]
+impl Drop for HookGuard {
+  fn drop(&mut self) {
+    #[cfg(debug_assertions)]
+    CURRENT_FRAME.with(|frame| {
+      if let Some(frame) = frame.borrow_mut().as_mut() {
+        frame.depth -= 1;
+      }
+    });
+
+    #[cfg(feature = "debug")]
+    HOOK_DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+  }
+}
+
+/// Records a call of the given hook and fails if it doesn't match the hook
+/// called at the same position during the previous render.
+///
+/// Has to be called at the beginning of every public hook.
+pub(crate) fn check_hook(hook: &'static str) -> HookGuard {
+  #[cfg(feature = "debug")]
+  HOOK_DEPTH.with(|depth| depth.set(depth.get() + 1));
+
+  #[cfg(debug_assertions)]
+  {
+    let error = CURRENT_FRAME.with(|frame| {
+      let mut frame = frame.borrow_mut();
+      let frame = frame.as_mut()?;
+
+      frame.depth += 1;
+
+      if frame.depth > 1 {
+        // Hooks called by other hooks are not recorded
+        return None;
+      }
+
+      let index = frame.hooks.len();
+      frame.hooks.push(hook);
+
+      match frame.previous.as_ref().map(|previous| previous.get(index)) {
+        Some(Some(&expected)) if expected != hook => Some((
+          frame.component,
+          format!(
+            "hook #{} is `{hook}`, but it was `{expected}` during the \
+            previous render",
+            index + 1
+          ),
+        )),
+        Some(None) => Some((
+          frame.component,
+          format!(
+            "rendered more hooks than during the previous render, hook #{} \
+            is `{hook}`",
+            index + 1
+          ),
+        )),
+        _ => None,
+      }
+    });
+
+    if let Some((component, message)) = error {
+      fail(component, &message);
+    }
+  }
+
+  #[cfg(not(debug_assertions))]
+  let _ = hook;
+
+  HookGuard
+}
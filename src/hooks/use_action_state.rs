@@ -0,0 +1,325 @@
+use super::{
+  check_hook, use_memo, use_ref, use_state, Deps, Dispatch, Memo, RefContainer,
+  State,
+};
+#[cfg(not(native_runtime))]
+use crate::{react_bindings, Callback, Void};
+#[cfg(not(native_runtime))]
+use js_sys::Function;
+use std::{cell::Ref, collections::VecDeque, fmt::Debug, rc::Rc};
+#[cfg(feature = "futures")]
+use std::{future::Future, pin::Pin};
+use wasm_bindgen::UnwrapThrowExt;
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{JsCast, JsValue};
+
+enum ActionResult<T> {
+  Ready(T),
+  #[cfg(feature = "futures")]
+  Pending(Pin<Box<dyn Future<Output = T>>>),
+}
+
+type ActionFn<T, P> = Rc<dyn Fn(&T, P) -> ActionResult<T>>;
+
+/// Allows access to the state persisted with [`use_action_state()`].
+#[derive(Debug)]
+pub struct ActionState<T, P> {
+  state: State<T>,
+  is_pending: bool,
+  dispatch: Memo<Dispatch<P>>,
+}
+
+impl<T: 'static, P: 'static> ActionState<T, P> {
+  /// Returns a reference to the value of the state.
+  pub fn state(&self) -> Ref<'_, T> {
+    self.state.value()
+  }
+
+  /// Returns whether an action is running or queued.
+  pub fn is_pending(&self) -> bool {
+    self.is_pending
+  }
+
+  /// Returns the handle to dispatch actions.
+  pub fn dispatch(&self) -> Dispatch<P> {
+    self.dispatch.value().clone()
+  }
+}
+
+impl<T, P> Clone for ActionState<T, P> {
+  fn clone(&self) -> Self {
+    Self {
+      state: self.state.clone(),
+      is_pending: self.is_pending,
+      dispatch: self.dispatch.clone(),
+    }
+  }
+}
+
+/// Calls the action of the latest render with the next queued payload.
+fn run_next<T: 'static, P: 'static>(
+  state: &State<T>,
+  action: &RefContainer<Option<ActionFn<T, P>>>,
+  queue: &mut RefContainer<VecDeque<P>>,
+) -> Option<ActionResult<T>> {
+  let payload = queue.current_mut().pop_front()?;
+  let action = action.current().clone().expect_throw("no action available");
+  let result = action(&state.value(), payload);
+
+  Some(result)
+}
+
+/// Runs all queued actions one after another.
+#[cfg(native_runtime)]
+fn run_queue<T: 'static, P: 'static>(
+  mut state: State<T>,
+  action: RefContainer<Option<ActionFn<T, P>>>,
+  mut queue: RefContainer<VecDeque<P>>,
+  mut pending: State<bool>,
+) {
+  while let Some(result) = run_next(&state, &action, &mut queue) {
+    match result {
+      ActionResult::Ready(value) => state.set(|_| value),
+      #[cfg(feature = "futures")]
+      ActionResult::Pending(future) => {
+        super::spawn_local(async move {
+          let value = future.await;
+
+          state.set(|_| value);
+          run_queue(state, action, queue, pending);
+        });
+
+        return;
+      }
+    }
+  }
+
+  pending.set(|_| false);
+}
+
+fn use_action_state_inner<T, P>(
+  action: ActionFn<T, P>,
+  init: impl FnOnce() -> T,
+) -> ActionState<T, P>
+where
+  T: 'static,
+  P: 'static,
+{
+  let state = use_state(init);
+  let mut action_ref: RefContainer<Option<ActionFn<T, P>>> = use_ref(None);
+  let queue: RefContainer<VecDeque<P>> = use_ref(VecDeque::new());
+
+  // Always use the action of the latest render
+  action_ref.set_current(Some(action));
+
+  #[cfg(not(native_runtime))]
+  {
+    // React calls this function once for every dispatched action
+    let run = use_memo(
+      {
+        let state = state.clone();
+        let mut queue = queue.clone();
+
+        move || {
+          Callback::new(move |_: Void| {
+            match run_next(&state, &action_ref, &mut queue) {
+              None => JsValue::UNDEFINED,
+              Some(ActionResult::Ready(value)) => {
+                state.clone().set(|_| value);
+                JsValue::UNDEFINED
+              }
+              #[cfg(feature = "futures")]
+              Some(ActionResult::Pending(future)) => {
+                let mut state = state.clone();
+
+                wasm_bindgen_futures::future_to_promise(async move {
+                  let value = future.await;
+
+                  state.set(|_| value);
+                  Ok(JsValue::UNDEFINED)
+                })
+                .into()
+              }
+            }
+          })
+        }
+      },
+      Deps::none(),
+    );
+
+    let result = react_bindings::use_rust_action_state(&run.value().as_js());
+    let react_dispatch = result
+      .get(0)
+      .dyn_into::<Function>()
+      .expect_throw("unable to read dispatch function from action state");
+    let is_pending = result
+      .get(1)
+      .as_bool()
+      .expect_throw("unable to read pending state from action state");
+
+    let dispatch = use_memo(
+      move || {
+        Dispatch(Rc::new(move |payload| {
+          queue.clone().current_mut().push_back(payload);
+
+          react_dispatch
+            .call0(&JsValue::NULL)
+            .expect_throw("unable to call dispatch function");
+        }))
+      },
+      Deps::none(),
+    );
+
+    ActionState {
+      state,
+      is_pending,
+      dispatch,
+    }
+  }
+  #[cfg(native_runtime)]
+  {
+    let pending = use_state(|| false);
+    let is_pending = *pending.value();
+
+    let dispatch = use_memo(
+      {
+        let state = state.clone();
+
+        move || {
+          Dispatch(Rc::new(move |payload| {
+            queue.clone().current_mut().push_back(payload);
+
+            if !*pending.value() {
+              pending.clone().set(|_| true);
+              run_queue(
+                state.clone(),
+                action_ref.clone(),
+                queue.clone(),
+                pending.clone(),
+              );
+            }
+          }))
+        }
+      },
+      Deps::none(),
+    );
+
+    ActionState {
+      state,
+      is_pending,
+      dispatch,
+    }
+  }
+}
+
+/// Persists state which is updated by an action, e.g. when submitting a form.
+///
+/// Dispatching a payload will call the action of the latest render with the
+/// current state and the payload, and set the state to its return value.
+/// Actions are queued and run one after another inside of a transition, so
+/// [`use_optimistic()`](super::use_optimistic()) can be used to show the
+/// expected result in the meantime.
+///
+/// Requires React 19.
+///
+/// See [React documentation](https://react.dev/reference/react/useActionState).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let cart = use_action_state(
+///     |items: &Vec<&'static str>, item: &'static str| {
+///       let mut items = items.clone();
+///       items.push(item);
+///       items
+///     },
+///     Vec::new,
+///   );
+///
+///   let vnode = h!(div).build((
+///     h!(p).build(("Items: ", cart.state().len())),
+///     h!(button)
+///       .disabled(cart.is_pending())
+///       .on_click(&cart.dispatch().to_callback().premap(|_| "Apple"))
+///       .build("Add to cart"),
+///   ));
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_action_state<T, P>(
+  action: impl Fn(&T, P) -> T + 'static,
+  init: impl FnOnce() -> T,
+) -> ActionState<T, P>
+where
+  T: 'static,
+  P: 'static,
+{
+  let _guard = check_hook("use_action_state");
+
+  use_action_state_inner(
+    Rc::new(move |state: &T, payload| {
+      ActionResult::Ready(action(state, payload))
+    }),
+    init,
+  )
+}
+
+/// Same as [`use_action_state()`], but the action returns a future, e.g. to
+/// send data to a server. The next action will be called once the future has
+/// completed, and [`ActionState::is_pending()`] returns `true` in the meantime.
+///
+/// Requires the `futures` feature and React 19.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # async fn update_name(name: String) -> Result<String, String> { Ok(name) }
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let name = use_async_action_state(
+///     |_: &Result<String, String>, name: String| update_name(name),
+///     || Ok(String::new()),
+///   );
+///
+///   let vnode = h!(div).build((
+///     h!(button)
+///       .disabled(name.is_pending())
+///       .on_click(
+///         &name.dispatch().to_callback().premap(|_| "Alice".to_string()),
+///       )
+///       .build("Update"),
+///     match &*name.state() {
+///       Ok(name) => h!(p).build(("Name: ", &**name)),
+///       Err(err) => h!(p).build(("Error: ", &**err)),
+///     },
+///   ));
+///   vnode
+/// }
+/// # }
+/// ```
+#[cfg(feature = "futures")]
+pub fn use_async_action_state<T, P, F>(
+  action: impl Fn(&T, P) -> F + 'static,
+  init: impl FnOnce() -> T,
+) -> ActionState<T, P>
+where
+  T: 'static,
+  P: 'static,
+  F: Future<Output = T> + 'static,
+{
+  let _guard = check_hook("use_async_action_state");
+
+  use_action_state_inner(
+    Rc::new(move |state: &T, payload| {
+      ActionResult::Pending(Box::pin(action(state, payload)))
+    }),
+    init,
+  )
+}
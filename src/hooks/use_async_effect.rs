@@ -0,0 +1,175 @@
+use super::{check_hook, use_effect::use_effect_inner, Deps, IntoDestructor};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+use std::{
+  cell::RefCell,
+  future::Future,
+  pin::Pin,
+  rc::Rc,
+  task::{Context, Poll, Waker},
+};
+
+/// Spawns the given future on the current thread.
+///
+/// With the `testing` feature, the future will be driven by the test renderer
+/// inside of [`act()`](crate::testing::act()).
+pub(crate) fn spawn_local(future: impl Future<Output = ()> + 'static) {
+  #[cfg(not(native_testing))]
+  wasm_bindgen_futures::spawn_local(future);
+
+  #[cfg(native_testing)]
+  crate::testing::spawn_local(future);
+}
+
+/// A clean-up future which can be returned by an async effect. It will be
+/// spawned when the effect is cleaned up.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::hooks::*;
+/// # async fn connect() {}
+/// # async fn disconnect() {}
+/// # fn f() {
+/// use_async_effect(
+///   || async {
+///     connect().await;
+///     AsyncCleanup(disconnect())
+///   },
+///   Deps::none(),
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AsyncCleanup<F>(pub F);
+
+impl<F> IntoDestructor for AsyncCleanup<F>
+where
+  F: Future<Output = ()> + 'static,
+{
+  type Destructor = Box<dyn FnOnce()>;
+
+  fn into_destructor(self) -> Self::Destructor {
+    Box::new(move || spawn_local(self.0))
+  }
+}
+
+enum TaskState<G: IntoDestructor> {
+  Pending(Pin<Box<dyn Future<Output = G>>>, Option<Waker>),
+  Polling,
+  Done(G::Destructor),
+  Aborted,
+}
+
+/// Drives the future of an async effect unless it has been aborted.
+struct Task<G: IntoDestructor>(Rc<RefCell<TaskState<G>>>);
+
+impl<G: IntoDestructor> Future for Task<G> {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+    let mut future = match self.0.replace(TaskState::Polling) {
+      TaskState::Pending(future, _) => future,
+      state => {
+        *self.0.borrow_mut() = state;
+        return Poll::Ready(());
+      }
+    };
+
+    // The state must not be borrowed while polling, since the future might
+    // cause the effect to be cleaned up
+    let result = future.as_mut().poll(cx);
+    let mut state = self.0.borrow_mut();
+
+    match (&*state, result) {
+      (TaskState::Polling, Poll::Ready(destructor)) => {
+        *state = TaskState::Done(destructor.into_destructor());
+        Poll::Ready(())
+      }
+      (TaskState::Polling, Poll::Pending) => {
+        *state = TaskState::Pending(future, Some(cx.waker().clone()));
+        Poll::Pending
+      }
+      // The effect has been cleaned up while the future was resolving, so the
+      // clean-up has to run right away
+      (_, Poll::Ready(destructor)) => {
+        drop(state);
+        destructor.into_destructor()();
+        Poll::Ready(())
+      }
+      // The effect has been cleaned up in the meantime
+      (_, Poll::Pending) => Poll::Ready(()),
+    }
+  }
+}
+
+/// Same as [`use_effect()`](super::use_effect()), but runs a future which is
+/// created by the given function, e.g. to fetch data.
+///
+/// When the dependencies change or the component unmounts, the future will be
+/// aborted if it hasn't completed yet. Otherwise the clean-up function the
+/// future has resolved to will be called. The future can also resolve to an
+/// [`AsyncCleanup`] to run an asynchronous clean-up.
+///
+/// Requires the `futures` feature.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// #
+/// # async fn fetch(url: &str) -> String { String::new() }
+/// # struct C { url: &'static str }
+/// # impl C {
+/// #   fn f(&self) {
+/// let state = use_state(|| None);
+///
+/// use_async_effect({
+///   clones!(self.url, mut state);
+///
+///   move || async move {
+///     // Won't be set if the URL has changed in the meantime
+///     let response = fetch(url).await;
+///     state.set(|_| Some(response));
+///   }
+/// }, Deps::some(self.url));
+/// #
+/// #   }
+/// # }
+/// ```
+pub fn use_async_effect<F, G, D>(
+  effect: impl FnOnce() -> F + 'static,
+  deps: Deps<D>,
+) where
+  F: Future<Output = G> + 'static,
+  G: IntoDestructor + 'static,
+  D: PartialEq + 'static,
+{
+  let _guard = check_hook("use_async_effect");
+
+  let effect = move || {
+    let state =
+      Rc::new(RefCell::new(TaskState::Pending(Box::pin(effect()), None)));
+
+    spawn_local(Task(state.clone()));
+
+    move || match state.replace(TaskState::Aborted) {
+      TaskState::Pending(future, waker) => {
+        drop(future);
+
+        // Let the executor drop the task
+        if let Some(waker) = waker {
+          waker.wake();
+        }
+      }
+      TaskState::Done(destructor) => destructor(),
+      TaskState::Polling | TaskState::Aborted => {}
+    }
+  };
+
+  #[cfg(not(native_runtime))]
+  use_effect_inner(effect, deps, react_bindings::use_rust_effect);
+
+  #[cfg(native_runtime)]
+  use_effect_inner(effect, deps, false);
+}
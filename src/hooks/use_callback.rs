@@ -1,4 +1,4 @@
-use super::{use_memo, Deps};
+use super::{check_hook, use_memo, Deps};
 use crate::Callback;
 
 /// Returns a memoized callback.
@@ -8,6 +8,8 @@ where
   U: 'static,
   D: PartialEq + 'static,
 {
+  let _guard = check_hook("use_callback");
+
   let memo = use_memo(move || f, deps);
   let result = memo.value().clone();
 
@@ -1,26 +1,46 @@
+use super::check_hook;
 use std::{rc::Rc, thread::LocalKey};
 use wasm_bindgen::UnwrapThrowExt;
 
-use crate::{react_bindings, Context};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
+use crate::Context;
 
 /// Allows access to the current context value of the given context.
 ///
 /// See [`create_context()`](crate::create_context()) for usage.
 pub fn use_context<T>(context: &'static LocalKey<Context<T>>) -> Rc<T> {
-  let mut result = None;
+  let _guard = check_hook("use_context");
 
-  context.with(|context| {
-    react_bindings::use_rust_context(
-      context.as_ref(),
-      &mut |ref_container_value| {
-        result = Some(
-          ref_container_value
-            .value::<T>()
-            .expect_throw("mismatched context type"),
-        );
-      },
-    );
-  });
+  #[cfg(not(native_runtime))]
+  {
+    let mut result = None;
 
-  result.expect_throw("callback was not called")
+    context.with(|context| {
+      react_bindings::use_rust_context(
+        context.as_ref(),
+        &mut |ref_container_value| {
+          result = Some(
+            ref_container_value
+              .value::<T>()
+              .expect_throw("mismatched context type"),
+          );
+        },
+      );
+    });
+
+    result.expect_throw("callback was not called")
+  }
+  #[cfg(native_runtime)]
+  {
+    context.with(|context| match ssr::read_context(context.id) {
+      Some(value) => value
+        .downcast::<T>()
+        .ok()
+        .expect_throw("mismatched context type"),
+      None => context.default.clone(),
+    })
+  }
 }
@@ -0,0 +1,64 @@
+use super::check_hook;
+#[cfg(all(feature = "debug", not(native_runtime)))]
+use super::HookGuard;
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+use std::fmt::Debug;
+
+/// Adds a label with the [`Debug`] representation of the given value to a
+/// custom hook in [React DevTools](https://react.dev/learn/react-developer-tools).
+///
+/// The value is only formatted in debug builds or with the `debug` feature
+/// enabled, otherwise this hook does nothing.
+///
+/// With the `debug` feature enabled, [`use_state()`], [`use_memo()`], and
+/// [`use_ref()`] are labeled with their Rust type as well when they're called
+/// by components directly. Since they don't require their values to implement
+/// [`Debug`], use [`use_state_debug()`](super::use_state_debug()),
+/// [`use_memo_debug()`](super::use_memo_debug()), and
+/// [`use_ref_debug()`](super::use_ref_debug()) or this hook to inspect the
+/// values themselves.
+///
+/// See [React documentation](https://react.dev/reference/react/useDebugValue).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::hooks::*;
+/// fn use_online_status() -> bool {
+///   let is_online = use_state(|| true);
+///   let result = *is_online.value();
+///
+///   use_debug_value(&result);
+///   result
+/// }
+/// ```
+pub fn use_debug_value(value: &impl Debug) {
+  let _guard = check_hook("use_debug_value");
+
+  #[cfg(not(native_runtime))]
+  if cfg!(any(debug_assertions, feature = "debug")) {
+    react_bindings::use_debug_value(&format!("{value:?}").into());
+  }
+
+  #[cfg(native_runtime)]
+  let _ = value;
+}
+
+/// Labels a built-in hook with the given name and its Rust type, unless it has
+/// been called by another hook.
+#[cfg(all(feature = "debug", not(native_runtime)))]
+pub(crate) fn label_hook<T: ?Sized>(guard: &HookGuard, name: &str) {
+  if guard.is_outermost() {
+    react_bindings::use_debug_value(
+      &format!("{name}<{}>", std::any::type_name::<T>()).into(),
+    );
+  }
+}
+
+/// Labels a built-in hook with the given name and the [`Debug`] representation
+/// of its value.
+#[cfg(all(feature = "debug", not(native_runtime)))]
+pub(crate) fn label_hook_value(name: &str, value: &impl Debug) {
+  react_bindings::use_debug_value(&format!("{name}({value:?})").into());
+}
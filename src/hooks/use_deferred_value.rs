@@ -1,4 +1,5 @@
-use super::{use_ref, RefContainer};
+use super::{check_hook, use_ref, RefContainer};
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
 use std::cell::Ref;
 use wasm_bindgen::UnwrapThrowExt;
@@ -26,8 +27,15 @@ impl<T> Clone for DeferredValue<T> {
 /// Returns the given value, or in case of urgent updates, returns the previous
 /// value given.
 pub fn use_deferred_value<T: 'static>(value: T) -> DeferredValue<T> {
+  let _guard = check_hook("use_deferred_value");
+
   let mut ref_container = use_ref(None::<(T, u8)>);
 
+  // There are no urgent updates on the server
+  #[cfg(native_runtime)]
+  ref_container.set_current(Some((value, 0)));
+
+  #[cfg(not(native_runtime))]
   let deferred_counter = react_bindings::use_deferred_value(
     ref_container
       .current()
@@ -36,6 +44,7 @@ pub fn use_deferred_value<T: 'static>(value: T) -> DeferredValue<T> {
       .unwrap_or(0),
   );
 
+  #[cfg(not(native_runtime))]
   if Some(deferred_counter)
     != ref_container.current().as_ref().map(|current| current.1)
   {
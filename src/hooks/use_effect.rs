@@ -1,5 +1,9 @@
-use super::{use_ref, Deps};
+use super::{check_hook, use_ref, Deps};
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
+#[cfg(not(native_runtime))]
 use wasm_bindgen::{prelude::Closure, JsValue, UnwrapThrowExt};
 
 /// Denotes types that can be used as destructors for effects.
@@ -30,7 +34,8 @@ where
   }
 }
 
-fn use_effect_inner<G, D>(
+#[cfg(not(native_runtime))]
+pub(super) fn use_effect_inner<G, D>(
   effect: impl FnOnce() -> G + 'static,
   deps: Deps<D>,
   f: impl FnOnce(&JsValue, u8),
@@ -71,6 +76,30 @@ fn use_effect_inner<G, D>(
   f(effect.as_ref(), *counter);
 }
 
+#[cfg(native_runtime)]
+pub(super) fn use_effect_inner<G, D>(
+  effect: impl FnOnce() -> G + 'static,
+  deps: Deps<D>,
+  layout: bool,
+) where
+  G: IntoDestructor,
+  D: PartialEq + 'static,
+{
+  let mut ref_container = use_ref(None::<Deps<D>>);
+
+  let changed = match ref_container.current_mut().take() {
+    Some(old_deps) => deps.is_all() || old_deps != deps,
+    None => true,
+  };
+
+  ref_container.set_current(Some(deps));
+
+  // Effects are only collected and never run when rendering to a string
+  ssr::use_effect(layout, changed, move || {
+    Box::new(effect().into_destructor())
+  });
+}
+
 /// Runs a function which contains imperative code that may cause side-effects.
 ///
 /// The given function will run after render is committed to the screen when
@@ -104,7 +133,13 @@ where
   G: IntoDestructor,
   D: PartialEq + 'static,
 {
+  let _guard = check_hook("use_effect");
+
+  #[cfg(not(native_runtime))]
   use_effect_inner(effect, deps, react_bindings::use_rust_effect);
+
+  #[cfg(native_runtime)]
+  use_effect_inner(effect, deps, false);
 }
 
 /// Same as [`use_effect()`], but it fires synchronously after all DOM mutations.
@@ -117,5 +152,34 @@ pub fn use_layout_effect<G, D>(
   G: IntoDestructor,
   D: PartialEq + 'static,
 {
+  let _guard = check_hook("use_layout_effect");
+
+  #[cfg(not(native_runtime))]
   use_effect_inner(effect, deps, react_bindings::use_rust_layout_effect);
+
+  #[cfg(native_runtime)]
+  use_effect_inner(effect, deps, true);
+}
+
+/// Same as [`use_effect()`], but it fires before any DOM mutations, e.g. to
+/// inject styles before layout effects read the layout.
+///
+/// With the `native-runtime` feature, insertion effects are treated like layout
+/// effects.
+///
+/// See [React documentation](https://react.dev/reference/react/useInsertionEffect).
+pub fn use_insertion_effect<G, D>(
+  effect: impl FnOnce() -> G + 'static,
+  deps: Deps<D>,
+) where
+  G: IntoDestructor,
+  D: PartialEq + 'static,
+{
+  let _guard = check_hook("use_insertion_effect");
+
+  #[cfg(not(native_runtime))]
+  use_effect_inner(effect, deps, react_bindings::use_rust_insertion_effect);
+
+  #[cfg(native_runtime)]
+  use_effect_inner(effect, deps, true);
 }
@@ -0,0 +1,73 @@
+use super::{check_hook, use_memo, use_ref, Deps, RefContainer};
+use crate::Callback;
+use std::rc::Rc;
+use wasm_bindgen::UnwrapThrowExt;
+
+type EventFn<T, U> = Rc<dyn Fn(T) -> U>;
+
+/// Returns a stable [`Callback`] which always calls the given closure of the
+/// latest render, so it sees the latest props and state without being a
+/// dependency of effects.
+///
+/// The returned callback stays the same throughout the entire lifetime of the
+/// component. It is meant to be called from effects, not during render. The
+/// closure may rerender the component or call the callback again while it's
+/// running.
+///
+/// See [React documentation](https://react.dev/reference/react/useEffectEvent).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # fn connect(room: &str, on_connected: Callback<()>) -> impl FnOnce() { || () }
+/// # fn show_notification(message: &str, theme: &str) {}
+/// # struct ChatRoom { room: &'static str, theme: &'static str }
+/// # impl ChatRoom {
+/// #   fn f(&self) {
+/// let on_connected = use_effect_event({
+///   clones!(self.theme);
+///
+///   move |_| show_notification("Connected!", theme)
+/// });
+///
+/// use_effect({
+///   clones!(self.room);
+///
+///   // Changing the theme won't reconnect
+///   move || connect(room, on_connected)
+/// }, Deps::some(self.room));
+/// #
+/// #   }
+/// # }
+/// ```
+pub fn use_effect_event<T, U>(f: impl Fn(T) -> U + 'static) -> Callback<T, U>
+where
+  T: 'static,
+  U: 'static,
+{
+  let _guard = check_hook("use_effect_event");
+
+  let mut latest: RefContainer<Option<EventFn<T, U>>> = use_ref(None);
+
+  // Always use the closure of the latest render
+  latest.set_current(Some(Rc::new(f)));
+
+  let callback = use_memo(
+    move || {
+      Callback::new(move |arg| {
+        // Don't hold the borrow while calling, the closure might rerender
+        let f = latest
+          .current()
+          .clone()
+          .expect_throw("no effect event available");
+
+        f(arg)
+      })
+    },
+    Deps::none(),
+  );
+
+  let result = callback.value().clone();
+  result
+}
@@ -1,6 +1,19 @@
+use super::check_hook;
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
 
 /// Returns a unique component ID which is stable across server and client.
 pub fn use_id() -> String {
-  react_bindings::use_id()
+  let _guard = check_hook("use_id");
+
+  #[cfg(not(native_runtime))]
+  {
+    react_bindings::use_id()
+  }
+  #[cfg(native_runtime)]
+  {
+    ssr::use_id()
+  }
 }
@@ -0,0 +1,194 @@
+use super::{check_hook, use_layout_effect, Deps, JsRefContainer};
+use crate::{Callback, FromJsReturn};
+#[cfg(not(native_runtime))]
+use js_sys::{Function, Object, Reflect};
+use std::any::Any;
+#[cfg(not(native_runtime))]
+use wasm_bindgen::JsCast;
+use wasm_bindgen::{
+  convert::{FromWasmAbi, IntoWasmAbi},
+  prelude::*,
+};
+
+#[wasm_bindgen]
+extern "C" {
+  /// A JS object which exposes methods of a component to its parent, created
+  /// with [`use_imperative_handle()`].
+  #[wasm_bindgen(extends = js_sys::Object, is_type_of = JsValue::is_object)]
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  pub type ImperativeHandle;
+}
+
+impl ImperativeHandle {
+  /// Returns the method with the given name as [`Callback`].
+  ///
+  /// The argument is converted into a [`JsValue`] and the return value is
+  /// converted with [`FromJsReturn`], see [`Callback::from_js_function()`].
+  pub fn method<T, U>(&self, name: &str) -> Option<Callback<T, U>>
+  where
+    T: Into<JsValue> + 'static,
+    U: FromJsReturn + 'static,
+  {
+    #[cfg(not(native_runtime))]
+    {
+      Reflect::get(self, &name.into())
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()
+        .map(Callback::from_js_function)
+    }
+    #[cfg(native_runtime)]
+    {
+      let _ = name;
+      None
+    }
+  }
+}
+
+/// Builds an [`ImperativeHandle`] for [`use_imperative_handle()`].
+pub struct ImperativeHandleBuilder {
+  #[cfg(not(native_runtime))]
+  object: Object,
+  callbacks: Vec<Box<dyn Any>>,
+}
+
+impl ImperativeHandleBuilder {
+  /// Creates a new builder for a handle without methods.
+  pub fn new() -> Self {
+    Self {
+      #[cfg(not(native_runtime))]
+      object: Object::new(),
+      callbacks: Vec::new(),
+    }
+  }
+
+  /// Adds a method with the given name to the handle, which calls the given
+  /// callback. The callback will be kept alive as long as the handle is set.
+  pub fn method<T, U>(mut self, name: &str, f: &Callback<T, U>) -> Self
+  where
+    T: FromWasmAbi + 'static,
+    U: IntoWasmAbi + 'static,
+  {
+    #[cfg(not(native_runtime))]
+    Reflect::set(&self.object, &name.into(), &f.as_js())
+      .expect_throw("cannot write into handle object");
+
+    #[cfg(native_runtime)]
+    let _ = name;
+
+    self.callbacks.push(Box::new(f.clone()));
+    self
+  }
+}
+
+impl Default for ImperativeHandleBuilder {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Exposes a handle with methods defined in Rust through the given ref
+/// container, e.g. one which has been passed down by the parent component as
+/// prop, so the parent can call them imperatively.
+///
+/// The handle will be created again whenever the given dependencies change and
+/// will be removed when the component unmounts. The ref container can also
+/// come from a JS parent, see [`export_components!`](crate::export_components).
+///
+/// See [React documentation](https://react.dev/reference/react/useImperativeHandle).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # use web_sys::Element;
+/// struct FancyList {
+///   handle: JsRefContainer<ImperativeHandle>,
+/// }
+///
+/// impl Component for FancyList {
+///   fn render(&self) -> VNode {
+///     let list = use_js_ref::<Element>(None);
+///
+///     use_imperative_handle(
+///       &self.handle,
+///       {
+///         clones!(list);
+///
+///         move || {
+///           ImperativeHandleBuilder::new().method(
+///             "scrollToTop",
+///             &Callback::new(move |_: Void| {
+///               if let Some(list) = list.current() {
+///                 list.set_scroll_top(0);
+///               }
+///             }),
+///           )
+///         }
+///       },
+///       Deps::none(),
+///     );
+///
+///     h!(ul).ref_container(&list).build(/* … */ ())
+///   }
+/// }
+///
+/// struct App;
+///
+/// impl Component for App {
+///   fn render(&self) -> VNode {
+///     let list_handle = use_js_ref::<ImperativeHandle>(None);
+///
+///     h!(div).build((
+///       h!(button)
+///         .on_click(&Callback::new({
+///           clones!(list_handle);
+///
+///           move |_| {
+///             if let Some(scroll_to_top) = list_handle
+///               .current()
+///               .and_then(|handle| handle.method::<Void, ()>("scrollToTop"))
+///             {
+///               scroll_to_top.call(Void);
+///             }
+///           }
+///         }))
+///         .build("Scroll to top"),
+///       FancyList {
+///         handle: list_handle,
+///       }
+///       .build(),
+///     ))
+///   }
+/// }
+/// ```
+pub fn use_imperative_handle<D>(
+  ref_container: &JsRefContainer<ImperativeHandle>,
+  create: impl FnOnce() -> ImperativeHandleBuilder + 'static,
+  deps: Deps<D>,
+) where
+  D: PartialEq + 'static,
+{
+  let _guard = check_hook("use_imperative_handle");
+
+  use_layout_effect(
+    {
+      let ref_container = ref_container.clone();
+
+      move || {
+        let builder = create();
+
+        #[cfg(not(native_runtime))]
+        ref_container.set_current(Some(builder.object.unchecked_ref()));
+
+        move || {
+          ref_container.set_current(None);
+
+          // Release the callbacks of the handle
+          drop(builder.callbacks);
+        }
+      }
+    },
+    deps,
+  );
+}
@@ -1,61 +1,144 @@
+use super::check_hook;
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
-use js_sys::Reflect;
+#[cfg(native_runtime)]
+use crate::ssr;
+#[cfg(not(native_runtime))]
+use js_sys::{Function, Reflect};
 use std::{fmt::Debug, marker::PhantomData};
-use wasm_bindgen::{intern, JsCast, JsValue, UnwrapThrowExt};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{intern, UnwrapThrowExt};
+use wasm_bindgen::{JsCast, JsValue};
 
 /// Allows access to the underlying JS data persisted with [`use_js_ref()`].
-pub struct JsRefContainer<T>(JsValue, PhantomData<T>);
+///
+/// A ref container can also wrap a ref callback passed by JS, e.g. to an
+/// exported component. In this case, [`JsRefContainer::set_current()`] calls
+/// the callback and [`JsRefContainer::current()`] always returns `None`.
+///
+/// With the `native-runtime` feature enabled, the ref container will always be
+/// empty.
+pub struct JsRefContainer<T>(
+  #[cfg(not(native_runtime))] JsValue,
+  PhantomData<T>,
+);
 
 impl<T: JsCast> JsRefContainer<T> {
   /// Returns the underlying typed JS data.
   pub fn current(&self) -> Option<T> {
-    self.current_untyped().dyn_into::<T>().ok()
+    #[cfg(not(native_runtime))]
+    {
+      self.current_untyped().dyn_into::<T>().ok()
+    }
+    #[cfg(native_runtime)]
+    {
+      None
+    }
   }
 
   /// Returns the underlying JS data as [`JsValue`].
   pub fn current_untyped(&self) -> JsValue {
-    Reflect::get(&self.0, &intern("current").into())
-      .expect_throw("cannot read from ref container")
+    #[cfg(not(native_runtime))]
+    {
+      Reflect::get(&self.0, &intern("current").into())
+        .expect_throw("cannot read from ref container")
+    }
+    #[cfg(native_runtime)]
+    {
+      JsValue::NULL
+    }
   }
 
   /// Sets the underlying JS data.
   pub fn set_current(&self, value: Option<&T>) {
-    Reflect::set(
-      &self.0,
-      &intern("current").into(),
-      value.map(|t| t.as_ref()).unwrap_or(&JsValue::null()),
-    )
-    .expect_throw("cannot write into ref container");
+    #[cfg(not(native_runtime))]
+    {
+      let null = JsValue::null();
+      let value = value.map(|t| t.as_ref()).unwrap_or(&null);
+
+      match self.0.dyn_ref::<Function>() {
+        Some(ref_callback) => {
+          ref_callback
+            .call1(&JsValue::NULL, value)
+            .expect_throw("unable to call ref callback");
+        }
+        None => {
+          Reflect::set(&self.0, &intern("current").into(), value)
+            .expect_throw("cannot write into ref container");
+        }
+      }
+    }
+
+    #[cfg(native_runtime)]
+    let _ = value;
   }
 }
 
 impl<T> Debug for JsRefContainer<T> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_tuple("JsRefContainer").field(&self.0).finish()
+    #[cfg(not(native_runtime))]
+    {
+      f.debug_tuple("JsRefContainer").field(&self.0).finish()
+    }
+    #[cfg(native_runtime)]
+    {
+      f.debug_tuple("JsRefContainer").finish()
+    }
   }
 }
 
 impl<T> Clone for JsRefContainer<T> {
   fn clone(&self) -> Self {
-    Self(self.0.clone(), PhantomData)
+    #[cfg(not(native_runtime))]
+    {
+      Self(self.0.clone(), PhantomData)
+    }
+    #[cfg(native_runtime)]
+    {
+      Self(PhantomData)
+    }
   }
 }
 
 impl<T> AsRef<JsValue> for JsRefContainer<T> {
   fn as_ref(&self) -> &JsValue {
-    &self.0
+    #[cfg(not(native_runtime))]
+    {
+      &self.0
+    }
+    #[cfg(native_runtime)]
+    {
+      ssr::unsupported("converting ref containers into JS values")
+    }
   }
 }
 
 impl<T> From<JsRefContainer<T>> for JsValue {
   fn from(value: JsRefContainer<T>) -> Self {
-    value.0
+    #[cfg(not(native_runtime))]
+    {
+      value.0
+    }
+    #[cfg(native_runtime)]
+    {
+      let _ = value;
+      ssr::unsupported("converting ref containers into JS values")
+    }
   }
 }
 
 impl<T> From<JsValue> for JsRefContainer<T> {
   fn from(value: JsValue) -> Self {
-    Self(value, PhantomData)
+    #[cfg(not(native_runtime))]
+    {
+      Self(value, PhantomData)
+    }
+    #[cfg(native_runtime)]
+    {
+      // Ref containers always stay empty with the native runtime
+      let _ = value;
+      Self(PhantomData)
+    }
   }
 }
 
@@ -84,9 +167,19 @@ impl<T> From<JsValue> for JsRefContainer<T> {
 /// }
 /// ```
 pub fn use_js_ref<T: JsCast>(init: Option<T>) -> JsRefContainer<T> {
-  let ref_container = react_bindings::use_ref(
-    &init.map(|init| init.into()).unwrap_or(JsValue::null()),
-  );
+  let _guard = check_hook("use_js_ref");
 
-  JsRefContainer(ref_container, PhantomData)
+  #[cfg(not(native_runtime))]
+  {
+    let ref_container = react_bindings::use_ref(
+      &init.map(|init| init.into()).unwrap_or(JsValue::null()),
+    );
+
+    JsRefContainer(ref_container, PhantomData)
+  }
+  #[cfg(native_runtime)]
+  {
+    let _ = init;
+    JsRefContainer(PhantomData)
+  }
 }
@@ -1,5 +1,5 @@
-use super::{use_ref, Deps, RefContainer};
-use std::cell::Ref;
+use super::{check_hook, use_ref, Deps, RefContainer};
+use std::{cell::Ref, fmt::Debug};
 use wasm_bindgen::UnwrapThrowExt;
 
 /// Allows access to the underlying memoized data persisted with [`use_memo()`].
@@ -50,6 +50,11 @@ where
   T: 'static,
   D: PartialEq + 'static,
 {
+  let _guard = check_hook("use_memo");
+
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook::<T>(&_guard, "Memo");
+
   let mut deps_ref_container = use_ref(None::<Deps<D>>);
   let mut value_ref_container = use_ref(None::<T>);
 
@@ -67,3 +72,38 @@ where
 
   Memo(value_ref_container)
 }
+
+/// Like [`use_memo()`], but labels the hook with the [`Debug`] representation
+/// of its value in React DevTools when the `debug` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # struct C { a: u32, b: u32 };
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let (a, b) = (self.a, self.b);
+///   let sum = use_memo_debug(|| a + b, Deps::some((a, b)));
+///
+///   let vnode = h!(div).build(*sum.value());
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_memo_debug<T, D>(
+  create: impl FnOnce() -> T,
+  deps: Deps<D>,
+) -> Memo<T>
+where
+  T: Debug + 'static,
+  D: PartialEq + 'static,
+{
+  let _guard = check_hook("use_memo_debug");
+  let memo = use_memo(create, deps);
+
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook_value("Memo", &*memo.value());
+
+  memo
+}
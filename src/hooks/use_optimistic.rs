@@ -0,0 +1,235 @@
+use super::check_hook;
+#[cfg(not(native_runtime))]
+use super::{use_effect, use_ref, Deps, RefContainer};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+#[cfg(not(native_runtime))]
+use js_sys::{Array, Function};
+#[cfg(not(native_runtime))]
+use std::collections::BTreeMap;
+#[cfg(native_runtime)]
+use std::marker::PhantomData;
+use std::{fmt::Debug, rc::Rc};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+
+/// Stores the optimistic updates in Rust, React only keeps track of their ids.
+#[cfg(not(native_runtime))]
+struct OptimisticUpdates<A> {
+  next_id: u32,
+  updates: BTreeMap<u32, A>,
+}
+
+/// Allows access to the optimistic state returned by [`use_optimistic()`].
+pub struct Optimistic<T, A> {
+  value: Rc<T>,
+  is_pending: bool,
+  #[cfg(not(native_runtime))]
+  updates: RefContainer<OptimisticUpdates<A>>,
+  #[cfg(not(native_runtime))]
+  add: Function,
+  #[cfg(native_runtime)]
+  add: PhantomData<fn(A)>,
+}
+
+impl<T, A: 'static> Optimistic<T, A> {
+  /// Returns a reference to the optimistic state, i.e. the given state with
+  /// all pending optimistic updates applied.
+  pub fn value(&self) -> &T {
+    &self.value
+  }
+
+  /// Returns whether there are optimistic updates which are still pending.
+  pub fn is_pending(&self) -> bool {
+    self.is_pending
+  }
+
+  /// Applies the given update optimistically until the surrounding transition
+  /// or action has completed.
+  ///
+  /// This has to be called inside of [`Transition::start()`](super::Transition::start())
+  /// or an action of [`use_action_state()`](super::use_action_state()).
+  pub fn add(&self, update: A) {
+    #[cfg(not(native_runtime))]
+    {
+      let id = {
+        let mut updates = self.updates.clone();
+        let mut updates = updates.current_mut();
+        let id = updates.next_id;
+
+        updates.next_id += 1;
+        updates.updates.insert(id, update);
+        id
+      };
+
+      self
+        .add
+        .call1(&JsValue::NULL, &id.into())
+        .expect_throw("unable to call optimistic update");
+    }
+
+    #[cfg(native_runtime)]
+    let _ = update;
+  }
+}
+
+impl<T: Debug, A> Debug for Optimistic<T, A> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Optimistic")
+      .field("value", &self.value)
+      .field("is_pending", &self.is_pending)
+      .finish()
+  }
+}
+
+impl<T, A> Clone for Optimistic<T, A> {
+  fn clone(&self) -> Self {
+    Self {
+      value: self.value.clone(),
+      is_pending: self.is_pending,
+      #[cfg(not(native_runtime))]
+      updates: self.updates.clone(),
+      #[cfg(not(native_runtime))]
+      add: self.add.clone(),
+      #[cfg(native_runtime)]
+      add: PhantomData,
+    }
+  }
+}
+
+/// Shows a different state while an action is underway, e.g. to display a
+/// message in a chat before it has been sent.
+///
+/// Returns the given state with all pending optimistic updates applied with the
+/// reducer, which will be called on every render. Updates can be added with
+/// [`Optimistic::add()`] inside of a transition or action, and will be
+/// discarded once it has completed.
+///
+/// Requires React 19. With the `native-runtime` feature, optimistic updates
+/// will be discarded immediately.
+///
+/// See [React documentation](https://react.dev/reference/react/useOptimistic).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # fn send_message(messages: &Vec<String>, message: String) -> Vec<String> {
+/// #   todo!()
+/// # }
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let messages = use_action_state(send_message, Vec::new);
+///   let optimistic_messages = use_optimistic(
+///     &*messages.state(),
+///     |mut messages, message: String| {
+///       messages.push(format!("{message} (Sending…)"));
+///       messages
+///     },
+///   );
+///   let transition = use_transition();
+///
+///   let vnode = h!(div).build((
+///     h!(ul).build(
+///       optimistic_messages
+///         .value()
+///         .iter()
+///         .map(|message| h!(li).build(&**message))
+///         .collect::<VNode>(),
+///     ),
+///     h!(button)
+///       .on_click(&Callback::new({
+///         clones!(messages, optimistic_messages, mut transition);
+///
+///         move |_| {
+///           transition.start({
+///             clones!(messages, optimistic_messages);
+///
+///             move || {
+///               optimistic_messages.add("Hello!".to_string());
+///               messages.dispatch().call("Hello!".to_string());
+///             }
+///           });
+///         }
+///       }))
+///       .build("Send"),
+///   ));
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_optimistic<T, A>(
+  state: &T,
+  reducer: impl Fn(T, A) -> T,
+) -> Optimistic<T, A>
+where
+  T: Clone + 'static,
+  A: Clone + 'static,
+{
+  let _guard = check_hook("use_optimistic");
+
+  #[cfg(not(native_runtime))]
+  {
+    let updates = use_ref(OptimisticUpdates {
+      next_id: 0,
+      updates: BTreeMap::new(),
+    });
+
+    let result = react_bindings::use_rust_optimistic();
+    let ids = result
+      .get(0)
+      .dyn_into::<Array>()
+      .expect_throw("unable to read optimistic updates")
+      .iter()
+      .map(|id| id.as_f64().expect_throw("invalid optimistic update") as u32)
+      .collect::<Vec<_>>();
+    let add = result
+      .get(1)
+      .dyn_into::<Function>()
+      .expect_throw("unable to read add function from optimistic state");
+
+    let value = ids.iter().fold(state.clone(), |value, id| {
+      let update = updates.current().updates.get(id).cloned();
+
+      match update {
+        Some(update) => reducer(value, update),
+        None => value,
+      }
+    });
+
+    // Forget updates which have been discarded by React
+    use_effect(
+      {
+        let mut updates = updates.clone();
+        let next_id = updates.current().next_id;
+        let ids = ids.clone();
+
+        move || {
+          updates
+            .current_mut()
+            .updates
+            .retain(|id, _| *id >= next_id || ids.contains(id));
+        }
+      },
+      Deps::all(),
+    );
+
+    Optimistic {
+      value: Rc::new(value),
+      is_pending: !ids.is_empty(),
+      updates,
+      add,
+    }
+  }
+  #[cfg(native_runtime)]
+  {
+    let _ = reducer;
+
+    Optimistic {
+      value: Rc::new(state.clone()),
+      is_pending: false,
+      add: PhantomData,
+    }
+  }
+}
@@ -0,0 +1,166 @@
+use super::{
+  check_hook, use_memo, use_ref, use_state, Deps, Memo, RefContainer, State,
+};
+use crate::Callback;
+use std::{cell::Ref, fmt::Debug, rc::Rc};
+use wasm_bindgen::UnwrapThrowExt;
+
+type ReducerFn<T, A> = Rc<dyn Fn(T, A) -> T>;
+
+/// A handle to dispatch actions to the reducer of [`use_reducer()`] or the
+/// action of [`use_action_state()`](super::use_action_state()).
+///
+/// The handle stays the same throughout the entire lifetime of the component,
+/// so it is safe to put it into [`Deps`](crate::hooks::Deps) or props of
+/// [memoized](crate::Component::memoized()) components.
+pub struct Dispatch<A>(pub(super) Rc<dyn Fn(A)>);
+
+impl<A: 'static> Dispatch<A> {
+  /// Applies the given action to the state and rerenders the component. Actions
+  /// of [`use_action_state()`](super::use_action_state()) are queued instead.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the state is currently borrowed.
+  pub fn call(&self, action: A) {
+    (self.0)(action)
+  }
+
+  /// Returns a [`Callback`] that dispatches its argument.
+  pub fn to_callback(&self) -> Callback<A> {
+    self.clone().into()
+  }
+}
+
+impl<A> Debug for Dispatch<A> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("Dispatch(|_| { … })")
+  }
+}
+
+impl<A> PartialEq for Dispatch<A> {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl<A> Eq for Dispatch<A> {}
+
+impl<A> Clone for Dispatch<A> {
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<A: 'static> From<Dispatch<A>> for Callback<A> {
+  fn from(value: Dispatch<A>) -> Self {
+    Callback::new(move |action| value.call(action))
+  }
+}
+
+/// Allows access to the underlying state data persisted with [`use_reducer()`].
+#[derive(Debug)]
+pub struct Reducer<T, A> {
+  state: State<T>,
+  dispatch: Memo<Dispatch<A>>,
+}
+
+impl<T: 'static, A: 'static> Reducer<T, A> {
+  /// Returns a reference to the value of the state.
+  pub fn state(&self) -> Ref<'_, T> {
+    self.state.value()
+  }
+
+  /// Returns the handle to dispatch actions.
+  pub fn dispatch(&self) -> Dispatch<A> {
+    self.dispatch.value().clone()
+  }
+}
+
+impl<T, A> Clone for Reducer<T, A> {
+  fn clone(&self) -> Self {
+    Self {
+      state: self.state.clone(),
+      dispatch: self.dispatch.clone(),
+    }
+  }
+}
+
+/// An alternative to [`use_state()`] which manages state transitions through a
+/// reducer function.
+///
+/// Dispatching an action will apply the reducer of the latest render to the
+/// state and rerender the component, just like [`State::set()`] does.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// #
+/// enum Action {
+///   Increment,
+///   Decrement,
+/// }
+///
+/// fn reducer(count: i32, action: Action) -> i32 {
+///   match action {
+///     Action::Increment => count + 1,
+///     Action::Decrement => count - 1,
+///   }
+/// }
+///
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let counter = use_reducer(reducer, || 0);
+///   let dispatch = counter.dispatch();
+///
+///   let vnode = h!(div).build((
+///     h!(p).build(("Counter: ", *counter.state())),
+///     h!(button)
+///       .on_click(&dispatch.to_callback().premap(|_| Action::Increment))
+///       .build("Increment"),
+///     h!(button)
+///       .on_click(&dispatch.to_callback().premap(|_| Action::Decrement))
+///       .build("Decrement"),
+///   ));
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_reducer<T, A>(
+  reducer: impl Fn(T, A) -> T + 'static,
+  init: impl FnOnce() -> T,
+) -> Reducer<T, A>
+where
+  T: 'static,
+  A: 'static,
+{
+  let _guard = check_hook("use_reducer");
+
+  let state = use_state(init);
+  let mut reducer_ref: RefContainer<Option<ReducerFn<T, A>>> = use_ref(None);
+
+  // Always use the reducer of the latest render
+  reducer_ref.set_current(Some(Rc::new(reducer)));
+
+  let dispatch = use_memo(
+    {
+      let state = state.clone();
+
+      move || {
+        Dispatch(Rc::new(move |action| {
+          let reducer = reducer_ref
+            .current()
+            .clone()
+            .expect_throw("no reducer available");
+
+          state.clone().set(|value| reducer(value, action));
+        }))
+      }
+    },
+    Deps::none(),
+  );
+
+  Reducer { state, dispatch }
+}
@@ -1,4 +1,8 @@
+use super::check_hook;
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
 use std::{
   any::Any,
   cell::{Ref, RefCell, RefMut},
@@ -104,19 +108,59 @@ impl<T> Clone for RefContainer<T> {
 /// }
 /// ```
 pub fn use_ref<T: 'static>(init: T) -> RefContainer<T> {
-  let mut value = None;
+  let _guard = check_hook("use_ref");
 
-  react_bindings::use_rust_ref(
-    Closure::once(move || RefContainerValue(Rc::new(RefCell::new(init))))
-      .as_ref(),
-    &mut |ref_container_value| {
-      value = Some(
-        ref_container_value
-          .value::<RefCell<T>>()
-          .expect_throw("mismatched ref container type"),
-      );
-    },
-  );
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook::<T>(&_guard, "Ref");
 
-  RefContainer(value.expect_throw("callback was not called"))
+  #[cfg(not(native_runtime))]
+  {
+    let mut value = None;
+
+    react_bindings::use_rust_ref(
+      Closure::once(move || RefContainerValue(Rc::new(RefCell::new(init))))
+        .as_ref(),
+      &mut |ref_container_value| {
+        value = Some(
+          ref_container_value
+            .value::<RefCell<T>>()
+            .expect_throw("mismatched ref container type"),
+        );
+      },
+    );
+
+    RefContainer(value.expect_throw("callback was not called"))
+  }
+  #[cfg(native_runtime)]
+  {
+    RefContainer(ssr::use_hook(move || RefCell::new(init)))
+  }
+}
+
+/// Like [`use_ref()`], but labels the hook with the [`Debug`] representation
+/// of its value at render time in React DevTools when the `debug` feature is
+/// enabled.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let renders = use_ref_debug(0);
+///
+///   let vnode = h!(div).build(*renders.current());
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_ref_debug<T: Debug + 'static>(init: T) -> RefContainer<T> {
+  let _guard = check_hook("use_ref_debug");
+  let ref_container = use_ref(init);
+
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook_value("Ref", &*ref_container.current());
+
+  ref_container
 }
@@ -0,0 +1,446 @@
+use super::{check_hook, use_effect, use_sync_external_store, Deps};
+use crate::{Callback, Void};
+#[cfg(not(native_runtime))]
+use js_sys::Promise;
+use std::{
+  any::{Any, TypeId},
+  cell::RefCell,
+  collections::HashMap,
+  future::Future,
+  hash::Hash,
+  rc::Rc,
+};
+use wasm_bindgen::{JsValue, UnwrapThrowExt};
+
+#[cfg_attr(all(native_runtime, not(native_testing)), allow(dead_code))]
+enum Entry<T, E> {
+  Pending {
+    id: u64,
+    #[cfg(not(native_runtime))]
+    promise: Promise,
+  },
+  Ready(Rc<T>),
+  Failed(E),
+}
+
+impl<T, E: Clone> Clone for Entry<T, E> {
+  fn clone(&self) -> Self {
+    match self {
+      Entry::Pending {
+        id,
+        #[cfg(not(native_runtime))]
+        promise,
+      } => Entry::Pending {
+        id: *id,
+        #[cfg(not(native_runtime))]
+        promise: promise.clone(),
+      },
+      Entry::Ready(value) => Entry::Ready(value.clone()),
+      Entry::Failed(error) => Entry::Failed(error.clone()),
+    }
+  }
+}
+
+#[cfg_attr(all(native_runtime, not(native_testing)), allow(dead_code))]
+struct Cache<K, T, E> {
+  entries: HashMap<K, Entry<T, E>>,
+  /// The number of mounted components which use the resource with the key.
+  refs: HashMap<K, usize>,
+  /// Incremented whenever an entry has been invalidated or replaced.
+  generation: u64,
+  next_id: u64,
+  listeners: Vec<Callback<Void>>,
+}
+
+impl<K, T, E> Default for Cache<K, T, E> {
+  fn default() -> Self {
+    Self {
+      entries: HashMap::new(),
+      refs: HashMap::new(),
+      generation: 0,
+      next_id: 0,
+      listeners: Vec::new(),
+    }
+  }
+}
+
+/// A type-erased [`Cache`], so failed entries of all caches can be evicted.
+trait AnyCache {
+  fn as_any_mut(&mut self) -> &mut dyn Any;
+  fn evict_failed(&mut self);
+}
+
+impl<K, T, E> AnyCache for Cache<K, T, E>
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  fn as_any_mut(&mut self) -> &mut dyn Any {
+    self
+  }
+
+  fn evict_failed(&mut self) {
+    self
+      .entries
+      .retain(|_, entry| !matches!(entry, Entry::Failed(_)));
+  }
+}
+
+thread_local! {
+  static CACHES: RefCell<HashMap<TypeId, Box<dyn AnyCache>>> =
+    RefCell::new(HashMap::new());
+}
+
+fn with_cache<K, T, E, R>(f: impl FnOnce(&mut Cache<K, T, E>) -> R) -> R
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  CACHES.with(|caches| {
+    let mut caches = caches.borrow_mut();
+    let cache = caches
+      .entry(TypeId::of::<Cache<K, T, E>>())
+      .or_insert_with(|| Box::new(Cache::<K, T, E>::default()));
+
+    f(cache
+      .as_any_mut()
+      .downcast_mut()
+      .expect_throw("mismatched resource cache"))
+  })
+}
+
+/// Evicts all failed resources, so they will be fetched again once the
+/// components using them are rendered again, e.g. when an
+/// [`ErrorBoundary`](crate::ErrorBoundary) is reset.
+///
+/// Failed resources cannot be evicted automatically, since the components that
+/// have thrown their errors have never been mounted.
+pub(crate) fn evict_failed_resources() {
+  CACHES.with(|caches| {
+    for cache in caches.borrow_mut().values_mut() {
+      cache.evict_failed();
+    }
+  });
+}
+
+/// Rerenders all components which use resources of the given type.
+fn notify<K, T, E>()
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  let listeners = with_cache(|cache: &mut Cache<K, T, E>| {
+    cache.generation += 1;
+    cache.listeners.clone()
+  });
+
+  for listener in listeners {
+    listener.call(Void);
+  }
+}
+
+/// Marks the resource with the given key as used by a mounted component.
+fn retain<K, T, E>(key: K)
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  with_cache(|cache: &mut Cache<K, T, E>| {
+    *cache.refs.entry(key).or_default() += 1;
+  });
+}
+
+/// Releases the resource with the given key and evicts it from the cache if
+/// no mounted component uses it anymore.
+fn release<K, T, E>(key: K)
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  let unused = with_cache(|cache: &mut Cache<K, T, E>| {
+    let refs = cache.refs.get_mut(&key).expect_throw("unbalanced release");
+    *refs -= 1;
+
+    if *refs == 0 {
+      cache.refs.remove(&key);
+      true
+    } else {
+      false
+    }
+  });
+
+  if unused {
+    // Evict later, so components that are remounted immediately, e.g. in
+    // strict mode, keep the resource
+    super::spawn_local(async move {
+      with_cache(|cache: &mut Cache<K, T, E>| {
+        // Pending resources are still needed by suspended components
+        if !cache.refs.contains_key(&key)
+          && !matches!(cache.entries.get(&key), Some(Entry::Pending { .. }))
+        {
+          cache.entries.remove(&key);
+        }
+      });
+    });
+  }
+}
+
+/// Removes the cached resource with the given key, so components using it
+/// will fetch it again and suspend in the meantime.
+///
+/// Requires the `futures` feature.
+pub fn invalidate_resource<K, T, E>(key: &K)
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  with_cache(|cache: &mut Cache<K, T, E>| cache.entries.remove(key));
+  notify::<K, T, E>();
+}
+
+/// Removes all cached resources of the given type, so components using them
+/// will fetch them again and suspend in the meantime.
+///
+/// Resources are evicted automatically once no mounted component uses them
+/// anymore, except for resources that have never been used by a mounted
+/// component, e.g. ones provided with [`set_resource()`] or whose component
+/// has been removed while suspending.
+///
+/// Requires the `futures` feature.
+pub fn clear_resources<K, T, E>()
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  with_cache(|cache: &mut Cache<K, T, E>| cache.entries.clear());
+  notify::<K, T, E>();
+}
+
+/// Stores the given value as resource with the given key, e.g. to provide data
+/// that has been fetched beforehand for server-side rendering or tests.
+///
+/// Requires the `futures` feature.
+pub fn set_resource<K, T, E>(key: K, value: T)
+where
+  K: Hash + Eq + 'static,
+  T: 'static,
+  E: 'static,
+{
+  with_cache(|cache: &mut Cache<K, T, E>| {
+    cache.entries.insert(key, Entry::Ready(Rc::new(value)))
+  });
+  notify::<K, T, E>();
+}
+
+/// Spawns the given future and stores its result in the cache once it has
+/// completed, unless the entry has been invalidated in the meantime.
+#[cfg(any(not(native_runtime), native_testing))]
+fn start_fetch<K, T, E, F>(key: K, future: F) -> Entry<T, E>
+where
+  K: Hash + Eq + Clone + 'static,
+  T: 'static,
+  E: Clone + 'static,
+  F: Future<Output = Result<T, E>> + 'static,
+{
+  let id = with_cache(|cache: &mut Cache<K, T, E>| {
+    cache.next_id += 1;
+    cache.next_id
+  });
+
+  let task = {
+    let key = key.clone();
+
+    async move {
+      let result = future.await;
+
+      with_cache(|cache: &mut Cache<K, T, E>| {
+        if matches!(
+          cache.entries.get(&key),
+          Some(Entry::Pending { id: x, .. }) if *x == id
+        ) {
+          cache.entries.insert(
+            key,
+            match result {
+              Ok(value) => Entry::Ready(Rc::new(value)),
+              Err(error) => Entry::Failed(error),
+            },
+          );
+        }
+      });
+    }
+  };
+
+  #[cfg(not(native_runtime))]
+  let entry = Entry::Pending {
+    id,
+    // React will rerender the suspended components once the promise resolves
+    promise: wasm_bindgen_futures::future_to_promise(async move {
+      task.await;
+      Ok(JsValue::UNDEFINED)
+    }),
+  };
+
+  #[cfg(native_testing)]
+  let entry = {
+    super::spawn_local(task);
+    Entry::Pending { id }
+  };
+
+  with_cache(|cache: &mut Cache<K, T, E>| {
+    cache.entries.insert(key, entry.clone())
+  });
+
+  entry
+}
+
+/// Reads the resource with the given key from a cache, so it can be shared
+/// between components. If the resource hasn't been loaded yet, it will be
+/// fetched with the future returned by `fetch`, and the component suspends,
+/// i.e. the fallback of the nearest [`Suspense`](crate::Suspense) will be shown
+/// until the future completes.
+///
+/// If the future fails, the error will be thrown to the nearest
+/// [`ErrorBoundary`](crate::ErrorBoundary). Resetting the error boundary evicts
+/// the failed resource, so it will be fetched again. When `key` changes, the
+/// resource with the new key will be read instead. Use
+/// [`invalidate_resource()`] to fetch a resource again.
+///
+/// Resources are identified by the types of the key, value, and error, and the
+/// key itself, so use a dedicated key type for every kind of resource. They are
+/// evicted from the cache once no mounted component uses them anymore.
+///
+/// Suspending and throwing the error unwind the render function without running
+/// destructors, so don't hold [`RefCell`] borrows, locks or other guards while
+/// calling this hook, since they will never be released.
+///
+/// With the `native-runtime` feature, components cannot suspend. Resources
+/// have to be provided with [`set_resource()`] beforehand. With the `testing`
+/// feature, components suspend until their futures have been driven by
+/// [`act()`](crate::testing::act()).
+///
+/// Requires the `futures` feature.
+///
+/// # Example
+///
+/// ```
+/// # use std::rc::Rc;
+/// # use wasm_bindgen::JsValue;
+/// # use wasm_react::{*, hooks::*};
+/// #
+/// # async fn fetch_user(id: u32) -> Result<String, JsValue> { todo!() }
+/// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// struct UserId(u32);
+///
+/// struct UserName {
+///   id: u32,
+/// }
+///
+/// impl Component for UserName {
+///   fn render(&self) -> VNode {
+///     let name: Rc<String> =
+///       use_resource(UserId(self.id), |id| fetch_user(id.0));
+///
+///     h!(span).build(&**name)
+///   }
+/// }
+///
+/// struct App;
+///
+/// impl Component for App {
+///   fn render(&self) -> VNode {
+///     Suspense::new()
+///       .fallback("Loading…")
+///       .build(UserName { id: 1 }.build())
+///   }
+/// }
+/// ```
+pub fn use_resource<K, T, E, F>(key: K, fetch: impl FnOnce(&K) -> F) -> Rc<T>
+where
+  K: Hash + Eq + Clone + 'static,
+  T: 'static,
+  E: Into<JsValue> + Clone + 'static,
+  F: Future<Output = Result<T, E>> + 'static,
+{
+  let _guard = check_hook("use_resource");
+
+  // Rerender when resources are invalidated
+  use_sync_external_store(
+    |listener| {
+      with_cache(|cache: &mut Cache<K, T, E>| {
+        cache.listeners.push(listener.clone())
+      });
+
+      move || {
+        with_cache(|cache: &mut Cache<K, T, E>| {
+          cache.listeners.retain(|x| x != &listener)
+        })
+      }
+    },
+    || with_cache(|cache: &mut Cache<K, T, E>| cache.generation),
+  );
+
+  // Keep the resource in the cache while the component is mounted
+  use_effect(
+    {
+      let key = key.clone();
+
+      move || {
+        retain::<K, T, E>(key.clone());
+        move || release::<K, T, E>(key)
+      }
+    },
+    Deps::some(key.clone()),
+  );
+
+  let entry = match with_cache(|cache: &mut Cache<K, T, E>| {
+    cache.entries.get(&key).cloned()
+  }) {
+    Some(entry) => entry,
+    #[cfg(any(not(native_runtime), native_testing))]
+    None => start_fetch(key.clone(), fetch(&key)),
+    #[cfg(all(native_runtime, not(native_testing)))]
+    None => {
+      let _ = fetch;
+      panic!(
+        "use_resource() cannot fetch resources during server-side rendering, \
+        provide them with set_resource() beforehand"
+      )
+    }
+  };
+
+  match entry {
+    Entry::Ready(value) => value,
+    Entry::Failed(error) => {
+      #[cfg(not(native_runtime))]
+      {
+        super::abort_render();
+        wasm_bindgen::throw_val(error.into())
+      }
+      #[cfg(native_runtime)]
+      {
+        let _ = error;
+        panic!("resource failed to load")
+      }
+    }
+    #[cfg(not(native_runtime))]
+    Entry::Pending { promise, .. } => {
+      // Throwing a promise suspends the component
+      super::abort_render();
+      wasm_bindgen::throw_val(promise.into())
+    }
+    #[cfg(native_testing)]
+    Entry::Pending { .. } => crate::testing::suspend(),
+    #[cfg(all(native_runtime, not(native_testing)))]
+    Entry::Pending { .. } => panic!(
+      "use_resource() cannot suspend during server-side rendering, the \
+      resource is still pending"
+    ),
+  }
+}
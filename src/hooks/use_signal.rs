@@ -0,0 +1,120 @@
+use super::{check_hook, use_effect, use_memo, Deps};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
+use crate::Signal;
+use std::{cell::Ref, rc::Rc};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{JsValue, UnwrapThrowExt};
+
+/// Creates a [`Signal`] which is persisted throughout the entire lifetime of
+/// the component.
+///
+/// In contrast to [`use_state()`](super::use_state()), setting the signal won't
+/// rerender this component unless it reads the signal with
+/// [`use_signal_value()`].
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # struct Count { count: Signal<i32> }
+/// # impl Component for Count {
+/// #   fn render(&self) -> VNode { VNode::new() }
+/// # }
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let count = use_signal(|| 0);
+///
+///   h!(div).build((
+///     h!(button)
+///       .on_click(&Callback::new({
+///         clones!(count);
+///
+///         move |_| count.set(|count| count + 1)
+///       }))
+///       .build("Increment"),
+///     // Only `Count` rerenders when clicking the button
+///     Count { count }.build(),
+///   ))
+/// }
+/// # }
+/// ```
+pub fn use_signal<T: 'static>(init: impl FnOnce() -> T) -> Signal<T> {
+  let _guard = check_hook("use_signal");
+
+  let signal = use_memo(move || Signal::new(init()), Deps::none());
+  let result = signal.value().clone();
+  result
+}
+
+/// Subscribes the component to the given [`Signal`] and returns a reference to
+/// its value.
+///
+/// The component will rerender whenever the signal changes, while all other
+/// components stay untouched unless they read the signal as well.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// struct Count {
+///   count: Signal<i32>,
+/// }
+///
+/// impl Component for Count {
+///   fn render(&self) -> VNode {
+///     let count = *use_signal_value(&self.count);
+///
+///     h!(p).build(("Count: ", count))
+///   }
+/// }
+/// ```
+pub fn use_signal_value<T: 'static>(signal: &Signal<T>) -> Ref<'_, T> {
+  let _guard = check_hook("use_signal_value");
+
+  #[cfg(not(native_runtime))]
+  let update = react_bindings::use_rust_state();
+  #[cfg(native_runtime)]
+  let update = ssr::use_updater();
+
+  let rerender = move || {
+    #[cfg(not(native_runtime))]
+    update
+      .call0(&JsValue::NULL)
+      .expect_throw("unable to call state function");
+
+    #[cfg(native_runtime)]
+    update.schedule();
+  };
+
+  let value = signal.get();
+  let version = signal.version();
+
+  use_effect(
+    {
+      let signal = signal.clone();
+
+      move || {
+        let rerender = Rc::new(rerender);
+        let id = signal.subscribe(rerender.clone());
+
+        // The signal might have changed before we have subscribed. Computed
+        // signals without listeners only update their version once they're
+        // read, so read it first.
+        drop(signal.get());
+
+        if signal.version() != version {
+          rerender();
+        }
+
+        move || signal.unsubscribe(id)
+      }
+    },
+    Deps::some(signal.clone()),
+  );
+
+  value
+}
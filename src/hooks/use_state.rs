@@ -1,14 +1,23 @@
-use super::{use_ref, RefContainer};
+use super::{check_hook, use_ref, RefContainer};
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
+#[cfg(not(native_runtime))]
 use js_sys::Function;
-use std::cell::Ref;
-use wasm_bindgen::{JsValue, UnwrapThrowExt};
+use std::{cell::Ref, fmt::Debug};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::JsValue;
+use wasm_bindgen::UnwrapThrowExt;
 
 /// Allows access to the underlying state data persisted with [`use_state()`].
 #[derive(Debug)]
 pub struct State<T> {
   ref_container: RefContainer<Option<T>>,
+  #[cfg(not(native_runtime))]
   update: Function,
+  #[cfg(native_runtime)]
+  update: ssr::Updater,
 }
 
 impl<T: 'static> State<T> {
@@ -30,10 +39,15 @@ impl<T: 'static> State<T> {
     let new_value = value.map(|value| mutator(value));
 
     self.ref_container.set_current(new_value);
+
+    #[cfg(not(native_runtime))]
     self
       .update
       .call0(&JsValue::NULL)
       .expect_throw("unable to call state update");
+
+    #[cfg(native_runtime)]
+    self.update.schedule();
   }
 }
 
@@ -82,16 +96,51 @@ impl<T> Clone for State<T> {
 /// # }
 /// ```
 pub fn use_state<T: 'static>(init: impl FnOnce() -> T) -> State<T> {
+  let _guard = check_hook("use_state");
+
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook::<T>(&_guard, "State");
+
   let mut ref_container = use_ref(None);
 
   if ref_container.current().is_none() {
     ref_container.set_current(Some(init()));
   }
 
-  let update = react_bindings::use_rust_state();
-
   State {
     ref_container,
-    update,
+    #[cfg(not(native_runtime))]
+    update: react_bindings::use_rust_state(),
+    #[cfg(native_runtime)]
+    update: ssr::use_updater(),
   }
 }
+
+/// Like [`use_state()`], but labels the hook with the [`Debug`] representation
+/// of its value in React DevTools when the `debug` feature is enabled.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let count = use_state_debug(|| 0);
+///
+///   let vnode = h!(div).build(*count.value());
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_state_debug<T: Debug + 'static>(
+  init: impl FnOnce() -> T,
+) -> State<T> {
+  let _guard = check_hook("use_state_debug");
+  let state = use_state(init);
+
+  #[cfg(all(feature = "debug", not(native_runtime)))]
+  super::label_hook_value("State", &*state.value());
+
+  state
+}
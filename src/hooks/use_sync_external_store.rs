@@ -0,0 +1,352 @@
+use super::{check_hook, use_ref, IntoDestructor, RefContainer};
+#[cfg(native_runtime)]
+use super::{use_effect, Deps};
+#[cfg(not(native_runtime))]
+use crate::react_bindings;
+#[cfg(native_runtime)]
+use crate::ssr;
+use crate::{Callback, Void};
+#[cfg(not(native_runtime))]
+use js_sys::Function;
+use std::cell::Ref;
+use wasm_bindgen::UnwrapThrowExt;
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{prelude::Closure, JsValue};
+
+#[cfg(not(native_runtime))]
+type SubscribeFn = Box<dyn FnMut(Callback<Void>) -> JsValue>;
+type GetSnapshotFn<T> = Box<dyn FnMut() -> T>;
+#[cfg(not(native_runtime))]
+type StoreClosures = (
+  Closure<dyn FnMut(Function) -> JsValue>,
+  Closure<dyn FnMut() -> u8>,
+  Closure<dyn FnMut() -> u8>,
+);
+
+/// Allows access to the underlying snapshot persisted with
+/// [`use_sync_external_store()`].
+#[derive(Debug)]
+pub struct SyncExternalStore<T>(RefContainer<Option<(T, u8)>>);
+
+impl<T: 'static> SyncExternalStore<T> {
+  /// Returns a reference to the latest snapshot of the store.
+  pub fn value(&self) -> Ref<'_, T> {
+    Ref::map(self.0.current(), |x| {
+      &x.as_ref().expect_throw("no snapshot available").0
+    })
+  }
+}
+
+impl<T> Clone for SyncExternalStore<T> {
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+/// Subscribes to an external store and rerenders the component whenever the
+/// snapshot of the store changes.
+///
+/// `subscribe` is called with a [`Callback`] which has to be called whenever
+/// the store changes. It can return a clean-up function to unsubscribe. The
+/// closure of the first render will be used for the entire lifetime of the
+/// component.
+///
+/// `get_snapshot` returns the current data of the store. Unlike its React
+/// counterpart, snapshots are compared with [`PartialEq`] in Rust, so it's fine
+/// to return a new value every time as long as it's equal to the previous one.
+///
+/// See [React documentation](https://react.dev/reference/react/useSyncExternalStore).
+///
+/// # Example
+///
+/// ```
+/// # use std::cell::RefCell;
+/// # use wasm_react::{*, hooks::*};
+/// #
+/// # struct Store {
+/// #   counter: i32,
+/// #   listeners: Vec<Callback<Void>>,
+/// # }
+/// #
+/// thread_local! {
+///   static STORE: RefCell<Store> = RefCell::new(Store {
+///     counter: 0,
+///     listeners: vec![],
+///   });
+/// }
+///
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let counter = use_sync_external_store(
+///     |listener| {
+///       STORE.with(|store| store.borrow_mut().listeners.push(listener.clone()));
+///
+///       move || {
+///         STORE.with(|store| {
+///           store.borrow_mut().listeners.retain(|x| x != &listener)
+///         });
+///       }
+///     },
+///     || STORE.with(|store| store.borrow().counter),
+///   );
+///
+///   let vnode = h!(div).build(("Counter: ", *counter.value()));
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_sync_external_store<T, G>(
+  subscribe: impl FnMut(Callback<Void>) -> G + 'static,
+  get_snapshot: impl FnMut() -> T + 'static,
+) -> SyncExternalStore<T>
+where
+  T: PartialEq + 'static,
+  G: IntoDestructor,
+{
+  let _guard = check_hook("use_sync_external_store");
+
+  use_sync_external_store_inner(
+    subscribe,
+    get_snapshot,
+    None::<GetSnapshotFn<T>>,
+  )
+}
+
+/// Same as [`use_sync_external_store()`], but `get_server_snapshot` returns
+/// the data of the store which has been used to render the component on the
+/// server.
+///
+/// It will be used during hydration and when rendering to a string with the
+/// `native-runtime` feature, so the first render on the client matches the
+/// HTML of the server. The component will rerender with the snapshot of
+/// `get_snapshot` afterwards if it differs.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # fn subscribe(listener: Callback<Void>) -> impl FnOnce() { || () }
+/// # fn is_online() -> bool { true }
+/// # struct C;
+/// # impl C {
+/// fn render(&self) -> VNode {
+///   let is_online = use_sync_external_store_with_server(
+///     subscribe,
+///     is_online,
+///     // Assume we're online on the server
+///     || true,
+///   );
+///
+///   let vnode = h!(p).build(if *is_online.value() { "Online" } else { "Offline" });
+///   vnode
+/// }
+/// # }
+/// ```
+pub fn use_sync_external_store_with_server<T, G>(
+  subscribe: impl FnMut(Callback<Void>) -> G + 'static,
+  get_snapshot: impl FnMut() -> T + 'static,
+  get_server_snapshot: impl FnMut() -> T + 'static,
+) -> SyncExternalStore<T>
+where
+  T: PartialEq + 'static,
+  G: IntoDestructor,
+{
+  let _guard = check_hook("use_sync_external_store_with_server");
+
+  use_sync_external_store_inner(
+    subscribe,
+    get_snapshot,
+    Some(Box::new(get_server_snapshot) as GetSnapshotFn<T>),
+  )
+}
+
+/// Stores the given snapshot unless it's equal to the current one and returns
+/// a counter which changes whenever the snapshot has changed.
+#[cfg(not(native_runtime))]
+fn update_snapshot<T: PartialEq + 'static>(
+  snapshot_ref: &mut RefContainer<Option<(T, u8)>>,
+  snapshot: T,
+) -> u8 {
+  let mut current = snapshot_ref.current_mut();
+
+  match current.as_ref() {
+    Some((old_snapshot, counter)) if *old_snapshot == snapshot => *counter,
+    Some((_, counter)) => {
+      // Snapshot changed
+      let counter = counter.wrapping_add(1);
+      *current = Some((snapshot, counter));
+      counter
+    }
+    None => {
+      *current = Some((snapshot, 0));
+      0
+    }
+  }
+}
+
+fn use_sync_external_store_inner<T, G>(
+  mut subscribe: impl FnMut(Callback<Void>) -> G + 'static,
+  get_snapshot: impl FnMut() -> T + 'static,
+  get_server_snapshot: Option<GetSnapshotFn<T>>,
+) -> SyncExternalStore<T>
+where
+  T: PartialEq + 'static,
+  G: IntoDestructor,
+{
+  #[cfg(not(native_runtime))]
+  {
+    let snapshot_ref = use_ref(None::<(T, u8)>);
+    let mut subscribe_ref = use_ref(None::<SubscribeFn>);
+    let mut get_snapshot_ref = use_ref(None::<GetSnapshotFn<T>>);
+    let mut get_server_snapshot_ref = use_ref(None::<GetSnapshotFn<T>>);
+    let mut closures_ref = use_ref(None::<StoreClosures>);
+
+    if subscribe_ref.current().is_none() {
+      subscribe_ref.set_current(Some(Box::new(move |callback| {
+        Closure::once_into_js(subscribe(callback).into_destructor())
+      })));
+    }
+
+    // Always read snapshots with the closure of the latest render
+    get_snapshot_ref.set_current(Some(Box::new(get_snapshot)));
+    get_server_snapshot_ref.set_current(get_server_snapshot);
+
+    if closures_ref.current().is_none() {
+      let subscribe_closure = Closure::new({
+        let mut subscribe_ref = subscribe_ref.clone();
+
+        move |on_store_change: Function| {
+          let callback = Callback::new(move |_: Void| {
+            on_store_change
+              .call0(&JsValue::NULL)
+              .expect_throw("unable to call store change listener");
+          });
+
+          let mut subscribe = subscribe_ref.current_mut();
+          subscribe
+            .as_mut()
+            .expect_throw("no subscribe function available")(callback)
+        }
+      });
+
+      let get_snapshot_closure = Closure::new({
+        let mut snapshot_ref = snapshot_ref.clone();
+        let mut get_snapshot_ref = get_snapshot_ref.clone();
+
+        move || {
+          let snapshot = {
+            let mut get_snapshot = get_snapshot_ref.current_mut();
+            get_snapshot
+              .as_mut()
+              .expect_throw("no snapshot function available")()
+          };
+
+          update_snapshot(&mut snapshot_ref, snapshot)
+        }
+      });
+
+      // React reads the server snapshot during hydration and compares it with
+      // the snapshot afterwards, so both share the same counter
+      let get_server_snapshot_closure = Closure::new({
+        let mut snapshot_ref = snapshot_ref.clone();
+        let mut get_server_snapshot_ref = get_server_snapshot_ref.clone();
+
+        move || {
+          let snapshot = {
+            let mut get_server_snapshot = get_server_snapshot_ref.current_mut();
+            get_server_snapshot
+              .as_mut()
+              .expect_throw("no server snapshot function available")(
+            )
+          };
+
+          update_snapshot(&mut snapshot_ref, snapshot)
+        }
+      });
+
+      closures_ref.set_current(Some((
+        subscribe_closure,
+        get_snapshot_closure,
+        get_server_snapshot_closure,
+      )));
+    }
+
+    {
+      let closures = closures_ref.current();
+      let (
+        subscribe_closure,
+        get_snapshot_closure,
+        get_server_snapshot_closure,
+      ) = closures
+        .as_ref()
+        .expect_throw("no store closures available");
+      let has_server_snapshot = get_server_snapshot_ref.current().is_some();
+      let undefined = JsValue::UNDEFINED;
+
+      react_bindings::use_sync_external_store(
+        subscribe_closure.as_ref(),
+        get_snapshot_closure.as_ref(),
+        if has_server_snapshot {
+          get_server_snapshot_closure.as_ref()
+        } else {
+          &undefined
+        },
+      );
+    }
+
+    SyncExternalStore(snapshot_ref)
+  }
+  #[cfg(native_runtime)]
+  {
+    let mut snapshot_ref = use_ref(None::<(T, u8)>);
+    let mut get_snapshot_ref = use_ref(None::<GetSnapshotFn<T>>);
+    let updater = ssr::use_updater();
+
+    // Effects never run when rendering to a string, so the store won't be
+    // subscribed to on the server
+    use_effect(
+      {
+        let snapshot_ref = snapshot_ref.clone();
+        let mut get_snapshot_ref = get_snapshot_ref.clone();
+
+        move || {
+          subscribe(Callback::new(move |_: Void| {
+            let snapshot = {
+              let mut get_snapshot = get_snapshot_ref.current_mut();
+              get_snapshot
+                .as_mut()
+                .expect_throw("no snapshot function available")()
+            };
+
+            // Like React, only rerender if the snapshot has changed since the
+            // last render
+            let changed = snapshot_ref
+              .current()
+              .as_ref()
+              .is_none_or(|(old_snapshot, _)| *old_snapshot != snapshot);
+
+            if changed {
+              updater.schedule();
+            }
+          }))
+        }
+      },
+      Deps::none(),
+    );
+
+    // Always read snapshots with the closure of the latest render
+    let mut get_snapshot: GetSnapshotFn<T> = Box::new(get_snapshot);
+
+    // The test renderer behaves like a client
+    let snapshot =
+      match get_server_snapshot.filter(|_| ssr::is_rendering_to_string()) {
+        Some(mut get_server_snapshot) => get_server_snapshot(),
+        None => get_snapshot(),
+      };
+
+    get_snapshot_ref.set_current(Some(get_snapshot));
+    snapshot_ref.set_current(Some((snapshot, 0)));
+    SyncExternalStore(snapshot_ref)
+  }
+}
@@ -4,6 +4,7 @@ use wasm_bindgen::{prelude::wasm_bindgen, UnwrapThrowExt};
 use crate::react_bindings;
 
 #[doc(hidden)]
+#[cfg_attr(native_runtime, allow(dead_code))]
 #[wasm_bindgen(js_name = __WasmReact_TmpRef)]
 pub struct TmpRef(Box<dyn Any>);
 
@@ -11,6 +12,7 @@ pub struct TmpRef(Box<dyn Any>);
 /// 
 /// The value will live until the next rerender. Callback functions will be 
 /// persisted this way.
+#[cfg_attr(native_runtime, allow(dead_code))]
 pub(crate) fn use_tmp_ref<T>(value: T, mut callback: impl FnMut(&T))
 where
   T: 'static,
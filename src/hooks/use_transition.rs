@@ -1,12 +1,19 @@
+use super::check_hook;
+#[cfg(not(native_runtime))]
 use js_sys::Function;
+#[cfg(feature = "futures")]
+use std::future::Future;
+#[cfg(not(native_runtime))]
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
+#[cfg(not(native_runtime))]
 use crate::react_bindings;
 
 /// Allows access to the transition state.
 #[derive(Debug, Clone)]
 pub struct Transition {
   is_pending: bool,
+  #[cfg(not(native_runtime))]
   start_transition: Function,
 }
 
@@ -18,11 +25,96 @@ impl Transition {
 
   /// Marks the updates in the given closure as transitions.
   pub fn start(&mut self, f: impl FnOnce() + 'static) {
+    #[cfg(not(native_runtime))]
     self
       .start_transition
       .call1(&JsValue::NULL, &Closure::once_into_js(f))
       .expect_throw("unable to call start function");
+
+    #[cfg(native_runtime)]
+    f();
   }
+
+  /// Runs the given future as transition. The transition stays pending until
+  /// the future has completed.
+  ///
+  /// The future runs outside of the scope of the transition, so its state
+  /// updates are not marked as transitions, just like updates after `await` in
+  /// React. Wrap them in [`start_transition()`] to mark them as transitions.
+  ///
+  /// Requires the `futures` feature and React 19.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use wasm_react::{*, hooks::*};
+  /// # async fn save(count: i32) -> i32 { count }
+  /// # fn f(count: State<i32>, mut transition: Transition) {
+  /// transition.start_async({
+  ///   let value = *count.value();
+  ///   let mut count = count.clone();
+  ///
+  ///   async move {
+  ///     let saved = save(value).await;
+  ///
+  ///     start_transition(move || count.set(|_| saved));
+  ///   }
+  /// });
+  /// # }
+  /// ```
+  #[cfg(feature = "futures")]
+  pub fn start_async(&mut self, future: impl Future<Output = ()> + 'static) {
+    #[cfg(not(native_runtime))]
+    self
+      .start_transition
+      .call1(&JsValue::NULL, &async_scope(future))
+      .expect_throw("unable to call start function");
+
+    #[cfg(native_runtime)]
+    super::spawn_local(future);
+  }
+}
+
+/// Returns a JS function which starts the given future and returns a promise
+/// that resolves once the future has completed.
+#[cfg(all(feature = "futures", not(native_runtime)))]
+fn async_scope(future: impl Future<Output = ()> + 'static) -> JsValue {
+  Closure::once_into_js(move || {
+    wasm_bindgen_futures::future_to_promise(async move {
+      future.await;
+      Ok(JsValue::UNDEFINED)
+    })
+  })
+}
+
+/// Marks the updates in the given closure as transitions. In contrast to
+/// [`Transition::start()`], this can also be called outside of components, but
+/// doesn't provide a pending state.
+///
+/// See [React documentation](https://react.dev/reference/react/startTransition).
+pub fn start_transition(f: impl FnOnce() + 'static) {
+  #[cfg(not(native_runtime))]
+  react_bindings::start_transition(&Closure::once_into_js(f));
+
+  #[cfg(native_runtime)]
+  f();
+}
+
+/// Runs the given future as transition, see [`Transition::start_async()`]. In
+/// contrast to [`Transition::start_async()`], this can also be called outside
+/// of components, but doesn't provide a pending state.
+///
+/// State updates of the future are not marked as transitions, wrap them in
+/// [`start_transition()`] instead.
+///
+/// Requires the `futures` feature and React 19.
+#[cfg(feature = "futures")]
+pub fn start_transition_async(future: impl Future<Output = ()> + 'static) {
+  #[cfg(not(native_runtime))]
+  react_bindings::start_transition(&async_scope(future));
+
+  #[cfg(native_runtime)]
+  super::spawn_local(future);
 }
 
 /// Returns a stateful value for the pending state of the transition, and a
@@ -58,19 +150,28 @@ impl Transition {
 /// # }
 /// ```
 pub fn use_transition() -> Transition {
-  let result = react_bindings::use_transition();
-
-  let is_pending = result
-    .get(0)
-    .as_bool()
-    .expect_throw("unable to read pending state from transition");
-  let start_transition = result
-    .get(1)
-    .dyn_into::<Function>()
-    .expect_throw("unable to read start function from transition");
-
-  Transition {
-    is_pending,
-    start_transition,
+  let _guard = check_hook("use_transition");
+
+  #[cfg(not(native_runtime))]
+  {
+    let result = react_bindings::use_transition();
+
+    let is_pending = result
+      .get(0)
+      .as_bool()
+      .expect_throw("unable to read pending state from transition");
+    let start_transition = result
+      .get(1)
+      .dyn_into::<Function>()
+      .expect_throw("unable to read start function from transition");
+
+    Transition {
+      is_pending,
+      start_transition,
+    }
+  }
+  #[cfg(native_runtime)]
+  {
+    Transition { is_pending: false }
   }
 }
@@ -0,0 +1,276 @@
+use crate::VNode;
+#[cfg(not(native_runtime))]
+use crate::{
+  props::{HType, H},
+  react_bindings, Callback, Void,
+};
+#[cfg(not(native_runtime))]
+use std::{borrow::Cow, rc::Weak};
+use std::{cell::RefCell, fmt::Debug, future::Future, pin::Pin, rc::Rc};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsValue;
+
+type RenderFn<P> = Rc<dyn Fn(P) -> VNode>;
+type LoadFuture<P> =
+  Pin<Box<dyn Future<Output = Result<RenderFn<P>, JsValue>>>>;
+type Loader<P> = Rc<dyn Fn() -> LoadFuture<P>>;
+
+#[cfg_attr(all(native_runtime, not(native_testing)), allow(dead_code))]
+enum LazyState<P> {
+  Unloaded,
+  Loading,
+  Loaded(RenderFn<P>),
+}
+
+/// Calls the loader if the component is unloaded and stores the resulting
+/// render function.
+#[cfg(any(not(native_runtime), native_testing))]
+fn load<P: 'static>(
+  state: &Rc<RefCell<LazyState<P>>>,
+  loader: &Loader<P>,
+) -> impl Future<Output = Result<(), JsValue>> {
+  let future = matches!(*state.borrow(), LazyState::Unloaded).then(|| {
+    *state.borrow_mut() = LazyState::Loading;
+    loader()
+  });
+  let state = state.clone();
+
+  async move {
+    if let Some(future) = future {
+      match future.await {
+        Ok(render) => *state.borrow_mut() = LazyState::Loaded(render),
+        Err(err) => {
+          // Allow loading again, e.g. after resetting an error boundary
+          *state.borrow_mut() = LazyState::Unloaded;
+          return Err(err);
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Creates a `React.lazy` component which calls the loader when it's rendered
+/// for the first time.
+///
+/// `React.lazy` remembers failed loads, so the type is discarded on failure
+/// and a new one will be created the next time the component is built.
+#[cfg(not(native_runtime))]
+fn create_lazy_type<P: 'static>(
+  state: &Rc<RefCell<LazyState<P>>>,
+  loader: &Loader<P>,
+  typ: &Rc<RefCell<Option<JsValue>>>,
+) -> JsValue {
+  let state = state.clone();
+  let loader = loader.clone();
+  let typ = Rc::downgrade(typ);
+
+  react_bindings::create_lazy(&Closure::once_into_js(move || {
+    wasm_bindgen_futures::future_to_promise(async move {
+      let result = load(&state, &loader).await;
+
+      if result.is_err() {
+        Weak::upgrade(&typ).and_then(|typ| typ.borrow_mut().take());
+      }
+
+      result.map(|_| JsValue::UNDEFINED)
+    })
+  }))
+}
+
+#[cfg(not(native_runtime))]
+struct LazyType(JsValue);
+
+#[cfg(not(native_runtime))]
+impl HType for LazyType {
+  fn as_js(&self) -> Cow<'_, JsValue> {
+    Cow::Borrowed(&self.0)
+  }
+}
+
+/// A component which is loaded on demand, created by [`lazy()`].
+///
+/// While loading, the fallback of the nearest [`Suspense`](crate::Suspense)
+/// will be shown. If loading fails, the error will be thrown to the nearest
+/// [`ErrorBoundary`](crate::ErrorBoundary).
+pub struct Lazy<P> {
+  state: Rc<RefCell<LazyState<P>>>,
+  #[cfg_attr(all(native_runtime, not(native_testing)), allow(dead_code))]
+  loader: Loader<P>,
+  #[cfg(not(native_runtime))]
+  typ: Rc<RefCell<Option<JsValue>>>,
+}
+
+impl<P: 'static> Lazy<P> {
+  /// Returns whether the component has been loaded already.
+  pub fn is_loaded(&self) -> bool {
+    matches!(*self.state.borrow(), LazyState::Loaded(_))
+  }
+
+  /// Returns a [`VNode`] of the component with the given props, which will
+  /// start loading the component when it's rendered for the first time.
+  ///
+  /// If loading fails, the component will be loaded again the next time it's
+  /// rendered, e.g. after resetting the error boundary.
+  ///
+  /// With the `testing` feature, the rendering component suspends until the
+  /// loader has been driven by [`act()`](crate::testing::act()).
+  ///
+  /// # Panics
+  ///
+  /// With the `native-runtime` feature but without the `testing` feature, there
+  /// is no executor that could drive the loader, so this panics if the
+  /// component hasn't been loaded already.
+  pub fn build(&self, props: P) -> VNode
+  where
+    P: Clone,
+  {
+    #[cfg(not(native_runtime))]
+    {
+      let state = self.state.clone();
+      let typ = self
+        .typ
+        .borrow_mut()
+        .get_or_insert_with(|| {
+          create_lazy_type(&self.state, &self.loader, &self.typ)
+        })
+        .clone();
+
+      H::new(LazyType(typ))
+        .attr_callback(
+          "render",
+          &Callback::new(move |_: Void| {
+            let render = match &*state.borrow() {
+              LazyState::Loaded(render) => render.clone(),
+              _ => {
+                wasm_bindgen::throw_str("lazy component has not been loaded")
+              }
+            };
+
+            JsValue::from(render(props.clone()))
+          }),
+        )
+        .build(())
+    }
+    #[cfg(native_runtime)]
+    {
+      #[cfg(native_testing)]
+      if matches!(*self.state.borrow(), LazyState::Unloaded) {
+        let future = load(&self.state, &self.loader);
+
+        crate::hooks::spawn_local(async move {
+          if future.await.is_err() {
+            panic!("unable to load lazy component");
+          }
+        });
+      }
+
+      #[cfg(native_testing)]
+      if !self.is_loaded() {
+        crate::testing::suspend();
+      }
+
+      let render = match &*self.state.borrow() {
+        LazyState::Loaded(render) => render.clone(),
+        _ => panic!(
+          "lazy component has not been loaded, it cannot be loaded while \
+          rendering on the server"
+        ),
+      };
+
+      render(props)
+    }
+  }
+}
+
+impl<P> Clone for Lazy<P> {
+  fn clone(&self) -> Self {
+    Self {
+      state: self.state.clone(),
+      loader: self.loader.clone(),
+      #[cfg(not(native_runtime))]
+      typ: self.typ.clone(),
+    }
+  }
+}
+
+impl<P> Debug for Lazy<P> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let state = match *self.state.borrow() {
+      LazyState::Unloaded => "Unloaded",
+      LazyState::Loading => "Loading",
+      LazyState::Loaded(_) => "Loaded",
+    };
+
+    f.debug_tuple("Lazy").field(&state).finish()
+  }
+}
+
+/// Creates a component which is loaded on demand with the given loader when
+/// it's rendered for the first time, e.g. to split large pages of an app.
+///
+/// The loader returns a future, e.g. one which fetches and instantiates a
+/// separate wasm module or imports a JS module, that resolves to a function
+/// which builds the loaded component with props of type `P`. This can be a
+/// Rust [`Component`](crate::Component) or an imported component. If the
+/// future resolves to an error, the loader will be called again the next time
+/// the component is rendered.
+///
+/// Create lazy components once, e.g. in a `thread_local!`, and not inside of
+/// render functions, otherwise React will lose their state on rerender.
+///
+/// Requires the `futures` feature.
+///
+/// See [React documentation](https://react.dev/reference/react/lazy).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_bindgen::JsValue;
+/// # use wasm_react::*;
+/// #
+/// # async fn load_admin_module() -> Result<(), JsValue> { Ok(()) }
+/// # struct AdminPage { user: u32 }
+/// # impl Component for AdminPage {
+/// #   fn render(&self) -> VNode { VNode::new() }
+/// # }
+/// thread_local! {
+///   static ADMIN_PAGE: Lazy<u32> = lazy(|| async {
+///     load_admin_module().await?;
+///
+///     Ok(|user| AdminPage { user }.build())
+///   });
+/// }
+///
+/// struct App;
+///
+/// impl Component for App {
+///   fn render(&self) -> VNode {
+///     Suspense::new()
+///       .fallback("Loading…")
+///       .build(ADMIN_PAGE.with(|page| page.build(1)))
+///   }
+/// }
+/// ```
+pub fn lazy<P, F, R>(loader: impl Fn() -> F + 'static) -> Lazy<P>
+where
+  P: 'static,
+  F: Future<Output = Result<R, JsValue>> + 'static,
+  R: Fn(P) -> VNode + 'static,
+{
+  let loader = move || {
+    let future = loader();
+
+    Box::pin(async move { Ok(Rc::new(future.await?) as RenderFn<P>) })
+      as LoadFuture<P>
+  };
+
+  Lazy {
+    state: Rc::new(RefCell::new(LazyState::Unloaded)),
+    loader: Rc::new(loader),
+    #[cfg(not(native_runtime))]
+    typ: Rc::new(RefCell::new(None)),
+  }
+}
@@ -10,14 +10,24 @@ mod builtin_components;
 mod callback;
 mod component;
 mod context;
+#[cfg(feature = "futures")]
+mod lazy;
 mod macros;
+mod panic_hook;
 mod prop_container;
+#[cfg(feature = "react-dom")]
+mod react_dom;
+mod signal;
 mod vnode;
 
 pub mod hooks;
 pub mod props;
 #[doc(hidden)]
 pub mod react_bindings;
+#[cfg(native_runtime)]
+pub mod ssr;
+#[cfg(native_testing)]
+pub mod testing;
 
 use props::Props;
 use wasm_bindgen::prelude::*;
@@ -26,11 +36,321 @@ pub use builtin_components::*;
 pub use callback::*;
 pub use component::*;
 pub use context::*;
+#[cfg(feature = "futures")]
+pub use lazy::*;
 #[doc(hidden)]
 pub use paste::paste;
+#[doc(hidden)]
+pub use web_sys;
 pub use prop_container::*;
+#[cfg(feature = "react-dom")]
+pub use react_dom::*;
+pub use signal::*;
 pub use vnode::*;
 
+/// Turns a function into a [`Component`] with the function arguments as props.
+///
+/// The macro generates a props struct with the same name as the function, a
+/// `new()` constructor taking all required props, setters for all optional
+/// props of type [`Option<T>`], and the [`Component`] implementation. The
+/// component will show up with the function name in the React Developer Tools.
+///
+/// Reference arguments like `&str` or `&T` will be stored as [`Rc<str>`] or `T`
+/// respectively in the props struct. All other arguments need to implement
+/// [`Clone`].
+///
+/// Requires the `macros` feature which is enabled by default.
+///
+/// [`Rc<str>`]: std::rc::Rc
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::*;
+/// use web_sys::MouseEvent;
+///
+/// #[component]
+/// fn Button(
+///   label: &str,
+///   on_click: Callback<MouseEvent>,
+///   tooltip: Option<String>,
+/// ) -> VNode {
+///   h!(button)
+///     .title(tooltip.as_deref().unwrap_or_default())
+///     .on_click(&on_click)
+///     .build(label)
+/// }
+///
+/// # fn f() -> VNode {
+/// Button::new("Click me!", Callback::noop())
+///   .tooltip("This is a tooltip")
+///   .build()
+/// # }
+/// ```
+///
+/// To be able to use [`Component::memoized()`], derive [`PartialEq`]:
+///
+/// ```
+/// # use wasm_react::*;
+/// #[component(derive(PartialEq))]
+/// fn Greeting(name: &str) -> VNode {
+///   h!(h1).build(("Hello ", name, "!"))
+/// }
+///
+/// # fn f() -> VNode {
+/// Greeting::new("World").memoized().build()
+/// # }
+/// ```
+///
+/// `key` and `ref` are reserved by React and cannot be used as prop names:
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// #[component]
+/// fn Item(key: String) -> VNode {
+///   h!(li).build(key)
+/// }
+/// ```
+///
+/// Neither can names of component methods like `build`, `memoized`, `new` or
+/// `render`, since the prop setters would shadow them:
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// #[component]
+/// fn Item(build: u32) -> VNode {
+///   h!(li).build(build)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// #[component]
+/// fn Item(new: bool) -> VNode {
+///   h!(li).build(if new { "New" } else { "Old" })
+/// }
+/// ```
+#[cfg(feature = "macros")]
+pub use wasm_react_macros::component;
+
+/// A JSX-like macro for building [`VNode`]s with markup syntax.
+///
+/// - Lowercase tags like `<div>` or tags with dashes like `<web-component>`
+///   create HTML elements with [`h!`]. Known attributes and events map to the
+///   methods of [`H<HtmlTag>`](crate::props::H), e.g. `class` and `onClick`
+///   will call [`class_name()`](crate::props::H::class_name()) and
+///   [`on_click()`](crate::props::H::on_click()) respectively. Attributes
+///   with dashes like `data-id` or written as string literals will be set with
+///   [`H::attr()`](crate::props::H::attr()). Names that look like a typo of
+///   a known attribute or event cause a compile error, as do HTML spellings
+///   like `tabindex` instead of React's `tabIndex`.
+/// - Other tags like `<Counter>` build Rust components defined with
+///   [`#[component]`](macro@component). Attributes set the props of the same
+///   name, children are passed as the prop `children`, and missing required
+///   props cause a compile error. Components implemented by hand can be
+///   included as a block, e.g. `{Counter { counter: 0 }.build()}`.
+/// - Tags prefixed with `js:` like `<js:MyComponent>` build components imported
+///   with [`import_components!`] and set their props with
+///   [`H::attr()`](crate::props::H::attr()).
+/// - `key` and `ref` set the [React key][key] and ref container respectively.
+///
+/// Text has to be written as string literals and Rust expressions need to be
+/// enclosed by braces. Attributes without a value are set to `true`.
+///
+/// Requires the `macros` feature which is enabled by default.
+///
+/// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # use wasm_bindgen::prelude::*;
+/// # import_components! { #[wasm_bindgen(inline_js = "")] MyComponent }
+/// #[component]
+/// fn Counter(counter: i32, label: Option<String>, children: VNode) -> VNode {
+///   html! {
+///     <p>{label.as_deref().unwrap_or("Counter")} ": " {counter} {children}</p>
+///   }
+/// }
+///
+/// struct App;
+///
+/// impl Component for App {
+///   fn render(&self) -> VNode {
+///     let counter = use_state(|| 0);
+///     let warning = *counter.value() > 10;
+///
+///     html! {
+///       <div id="app" class={classnames![."app".warning]}>
+///         <Counter counter={*counter.value()}>
+///           <strong>"!"</strong>
+///         </Counter>
+///
+///         <button
+///           data-action="increment"
+///           disabled={warning}
+///           onClick={Callback::new({
+///             clones!(mut counter);
+///             move |_| counter.set(|c| c + 1)
+///           })}
+///         >
+///           "Increment"
+///         </button>
+///
+///         <js:MyComponent prop="Hello World!" />
+///
+///         <ul>
+///           {(0..3).map(|i| html! { <li key={i}>{i}</li> }).collect::<VNode>()}
+///         </ul>
+///       </div>
+///     }
+///   }
+/// }
+/// ```
+///
+/// Refs of HTML elements can have the type of the DOM element or any of its
+/// base types, see [`HRef`](crate::props::HRef):
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # #[cfg(feature = "html-elements")]
+/// # fn f() -> VNode {
+/// let input_ref = use_js_ref::<web_sys::HtmlInputElement>(None);
+///
+/// html! { <input ref={input_ref} /> }
+/// # }
+/// ```
+///
+/// Missing required props won't compile:
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// #[component]
+/// fn Greeting(name: &str) -> VNode {
+///   h!(h1).build(("Hello ", name, "!"))
+/// }
+///
+/// # fn f() -> VNode {
+/// html! { <Greeting /> }
+/// # }
+/// ```
+///
+/// Typos in known attributes and events won't compile:
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f(handle_click: Callback<web_sys::MouseEvent>) -> VNode {
+/// html! { <button onClik={handle_click}>"Click me!"</button> }
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f() -> VNode {
+/// html! { <button disabeld>"Click me!"</button> }
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f() -> VNode {
+/// html! { <input plaecholder="Name" /> }
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f() -> VNode {
+/// html! { <div clas="app" /> }
+/// # }
+/// ```
+///
+/// HTML spellings of attributes won't compile either, use React's camelCase
+/// names like `tabIndex` and `readOnly` instead:
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f() -> VNode {
+/// html! { <div tabindex={0} /> }
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # use wasm_react::*;
+/// # fn f() -> VNode {
+/// html! { <input readonly /> }
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+pub use wasm_react_macros::html;
+
+/// Generates a TypeScript interface for the props of a component which will be
+/// used in the declarations of [`export_components!`].
+///
+/// The interface is named after the component with a `Props` suffix and maps
+/// each field to the TypeScript type of the corresponding JS value, e.g.
+/// numbers to `number`, [`Vec<T>`] to `T[]`, and [`Callback<T, U>`] to
+/// `(arg: T) => U`. Fields of type [`Option<T>`] are optional. Types that
+/// cannot be mapped are declared as `any`.
+///
+/// The serde attributes `rename`, `rename_all`, `skip`, and `default` are
+/// respected, so the interface matches the props accepted by `Deserialize`. Use
+/// `#[ts(type = "…")]` to override the type of a field and `#[ts(optional)]`
+/// to mark a field as optional.
+///
+/// Requires the `macros` feature which is enabled by default. Only components
+/// exported by their plain name are typed, others fall back to `any`.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::*;
+/// # use wasm_bindgen::prelude::*;
+/// # use web_sys::MouseEvent;
+/// #[derive(TypeScriptProps)]
+/// pub struct Counter {
+///   counter: i32,
+///   label: Option<String>,
+///   on_increment: Callback<MouseEvent>,
+///   #[ts(type = "\"small\" | \"large\"")]
+///   size: JsValue,
+/// }
+///
+/// # impl Component for Counter { fn render(&self) -> VNode { VNode::new() } }
+/// # impl TryFrom<JsValue> for Counter {
+/// #   type Error = JsValue;
+/// #   fn try_from(_: JsValue) -> Result<Self, Self::Error> { todo!() }
+/// # }
+/// export_components! { Counter }
+/// ```
+///
+/// This will generate the following TypeScript declarations:
+///
+/// ```ts
+/// export interface CounterProps {
+///   "counter": number;
+///   "label"?: string;
+///   "on_increment": (arg: MouseEvent) => void;
+///   "size": "small" | "large";
+/// }
+///
+/// export function Counter(props: CounterProps): any;
+/// ```
+#[cfg(feature = "macros")]
+pub use wasm_react_macros::TypeScriptProps;
+
+// Lookup table for the props types of exported components which will be
+// augmented by `#[derive(TypeScriptProps)]`.
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_PROPS_REGISTRY: &'static str = r#"
+interface __WasmReactPropsRegistry {}
+
+type __WasmReactProps<K> = K extends keyof __WasmReactPropsRegistry
+  ? __WasmReactPropsRegistry[K]
+  : any;
+"#;
+
 /// Contains all functions exported to JS by `wasm-react`. These functions should
 /// be called from JS only.
 #[doc(hidden)]
@@ -60,6 +380,68 @@ impl WasmReact {
   pub fn use_react(value: &JsValue) {
     react_bindings::use_react(value);
   }
+
+  /// Installs a panic hook that reports Rust panics to the console, together
+  /// with the panic message, its location, and the Rust component that was
+  /// rendering at the time, if any.
+  ///
+  /// Panics in render functions will also be reported to the nearest
+  /// [`ErrorBoundary`], which makes the panic message and the React component
+  /// stack available through [`CaughtError`]. Panics in event handlers and
+  /// effects are only reported to the console.
+  ///
+  /// Calling this function multiple times will result in no-ops. Previously
+  /// installed panic hooks, e.g. the one from `console_error_panic_hook`, are
+  /// still being called.
+  ///
+  /// # Example
+  ///
+  /// ```js
+  /// import init, { WasmReact } from "./path/to/pkg/project.js";
+  ///
+  /// async function main() {
+  ///   await init();
+  ///   WasmReact.installPanicHook();
+  /// }
+  ///
+  /// main();
+  /// ```
+  #[wasm_bindgen(js_name = installPanicHook)]
+  pub fn install_panic_hook() {
+    panic_hook::install_reporting_panic_hook();
+  }
+}
+
+#[cfg(feature = "react-dom")]
+#[wasm_bindgen]
+impl WasmReact {
+  /// Set the ReactDOM runtime that `wasm-react` should use. The given value
+  /// needs to provide `createRoot`, `hydrateRoot`, and `createPortal`, e.g.
+  /// the `ReactDOM` global or the merged `react-dom` and `react-dom/client`
+  /// modules.
+  ///
+  /// Calling this function multiple times will result in no-ops.
+  ///
+  /// # Example
+  ///
+  /// ```js
+  /// import React from "react";
+  /// import * as ReactDOM from "react-dom";
+  /// import * as ReactDOMClient from "react-dom/client";
+  /// import init, { WasmReact } from "./path/to/pkg/project.js";
+  ///
+  /// async function main() {
+  ///   await init();
+  ///   WasmReact.useReact(React);
+  ///   WasmReact.useReactDom({ ...ReactDOM, ...ReactDOMClient });
+  /// }
+  ///
+  /// main();
+  /// ```
+  #[wasm_bindgen(js_name = useReactDom)]
+  pub fn use_react_dom(value: &JsValue) {
+    react_bindings::use_react_dom(value);
+  }
 }
 
 /// The Rust equivalent to `React.createElement`. Use [`h!`] for a more
@@ -38,22 +38,204 @@
 ///
 /// // <div id="app" class="some-class warning">This is a warning!</div>
 /// ```
+///
+/// With the `html-elements` feature enabled, refs of HTML elements can have the
+/// type of the DOM element, which is checked against the tag:
+///
+/// ```
+/// # #[cfg(feature = "html-elements")]
+/// # mod m {
+/// # use wasm_react::{*, hooks::*};
+/// use web_sys::HtmlInputElement;
+///
+/// # struct C;
+/// # impl Component for C {
+/// # fn render(&self) -> VNode {
+/// let input = use_js_ref::<HtmlInputElement>(None);
+///
+/// h!(input).ref_container_typed(&input).build(())
+///
+/// // Does not compile:
+/// // h!(div).ref_container_typed(&input).build(())
+/// # }
+/// # }
+/// # }
+/// ```
 #[macro_export]
 macro_rules! h {
   ($tag:literal $( [$( #$id:literal )? $( .$( $classnames:tt )+ )?] )?) => {
-    $crate::props::H::new($crate::props::HtmlTag($tag)) $(
+    $crate::props::H::new(
+      $crate::props::HtmlTag::<$crate::html_element_type!($tag)>::new($tag)
+    ) $(
       $( .id($id) )?
       $( .class_name(&$crate::classnames![.$( $classnames )+]) )?
     )?
   };
   ($tag:ident $( [$( #$id:literal )? $( .$( $classnames:tt )+ )?] )?) => {
-    $crate::props::H::new($crate::props::HtmlTag(stringify!($tag))) $(
+    $crate::props::H::new(
+      $crate::props::HtmlTag::<$crate::html_element_type!($tag)>::new(
+        stringify!($tag)
+      )
+    ) $(
       $( .id($id) )?
       $( .class_name(&$crate::classnames![.$( $classnames )+]) )?
     )?
   };
 }
 
+/// Returns the type of the DOM element with the given tag name, which is used
+/// by [`h!`](crate::h!) to check the types of refs. Tags which exist in HTML
+/// and SVG, e.g. `a`, are typed as HTML elements, tags which are not listed
+/// here as [`Element`](web_sys::Element).
+#[cfg(feature = "html-elements")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! html_element_type {
+  (a) => { $crate::web_sys::HtmlAnchorElement };
+  (area) => { $crate::web_sys::HtmlAreaElement };
+  (audio) => { $crate::web_sys::HtmlAudioElement };
+  (base) => { $crate::web_sys::HtmlBaseElement };
+  (blockquote) => { $crate::web_sys::HtmlQuoteElement };
+  (body) => { $crate::web_sys::HtmlBodyElement };
+  (br) => { $crate::web_sys::HtmlBrElement };
+  (button) => { $crate::web_sys::HtmlButtonElement };
+  (canvas) => { $crate::web_sys::HtmlCanvasElement };
+  (caption) => { $crate::web_sys::HtmlTableCaptionElement };
+  (col) => { $crate::web_sys::HtmlTableColElement };
+  (colgroup) => { $crate::web_sys::HtmlTableColElement };
+  (data) => { $crate::web_sys::HtmlDataElement };
+  (datalist) => { $crate::web_sys::HtmlDataListElement };
+  (del) => { $crate::web_sys::HtmlModElement };
+  (details) => { $crate::web_sys::HtmlDetailsElement };
+  (dialog) => { $crate::web_sys::HtmlDialogElement };
+  (div) => { $crate::web_sys::HtmlDivElement };
+  (dl) => { $crate::web_sys::HtmlDListElement };
+  (embed) => { $crate::web_sys::HtmlEmbedElement };
+  (fieldset) => { $crate::web_sys::HtmlFieldSetElement };
+  (form) => { $crate::web_sys::HtmlFormElement };
+  (h1) => { $crate::web_sys::HtmlHeadingElement };
+  (h2) => { $crate::web_sys::HtmlHeadingElement };
+  (h3) => { $crate::web_sys::HtmlHeadingElement };
+  (h4) => { $crate::web_sys::HtmlHeadingElement };
+  (h5) => { $crate::web_sys::HtmlHeadingElement };
+  (h6) => { $crate::web_sys::HtmlHeadingElement };
+  (head) => { $crate::web_sys::HtmlHeadElement };
+  (hr) => { $crate::web_sys::HtmlHrElement };
+  (html) => { $crate::web_sys::HtmlHtmlElement };
+  (iframe) => { $crate::web_sys::HtmlIFrameElement };
+  (img) => { $crate::web_sys::HtmlImageElement };
+  (input) => { $crate::web_sys::HtmlInputElement };
+  (ins) => { $crate::web_sys::HtmlModElement };
+  (label) => { $crate::web_sys::HtmlLabelElement };
+  (legend) => { $crate::web_sys::HtmlLegendElement };
+  (li) => { $crate::web_sys::HtmlLiElement };
+  (link) => { $crate::web_sys::HtmlLinkElement };
+  (map) => { $crate::web_sys::HtmlMapElement };
+  (meta) => { $crate::web_sys::HtmlMetaElement };
+  (meter) => { $crate::web_sys::HtmlMeterElement };
+  (object) => { $crate::web_sys::HtmlObjectElement };
+  (ol) => { $crate::web_sys::HtmlOListElement };
+  (optgroup) => { $crate::web_sys::HtmlOptGroupElement };
+  (option) => { $crate::web_sys::HtmlOptionElement };
+  (output) => { $crate::web_sys::HtmlOutputElement };
+  (p) => { $crate::web_sys::HtmlParagraphElement };
+  (picture) => { $crate::web_sys::HtmlPictureElement };
+  (pre) => { $crate::web_sys::HtmlPreElement };
+  (progress) => { $crate::web_sys::HtmlProgressElement };
+  (q) => { $crate::web_sys::HtmlQuoteElement };
+  (script) => { $crate::web_sys::HtmlScriptElement };
+  (select) => { $crate::web_sys::HtmlSelectElement };
+  (slot) => { $crate::web_sys::HtmlSlotElement };
+  (source) => { $crate::web_sys::HtmlSourceElement };
+  (span) => { $crate::web_sys::HtmlSpanElement };
+  (style) => { $crate::web_sys::HtmlStyleElement };
+  (table) => { $crate::web_sys::HtmlTableElement };
+  (tbody) => { $crate::web_sys::HtmlTableSectionElement };
+  (td) => { $crate::web_sys::HtmlTableCellElement };
+  (template) => { $crate::web_sys::HtmlTemplateElement };
+  (textarea) => { $crate::web_sys::HtmlTextAreaElement };
+  (tfoot) => { $crate::web_sys::HtmlTableSectionElement };
+  (th) => { $crate::web_sys::HtmlTableCellElement };
+  (thead) => { $crate::web_sys::HtmlTableSectionElement };
+  (time) => { $crate::web_sys::HtmlTimeElement };
+  (title) => { $crate::web_sys::HtmlTitleElement };
+  (tr) => { $crate::web_sys::HtmlTableRowElement };
+  (track) => { $crate::web_sys::HtmlTrackElement };
+  (ul) => { $crate::web_sys::HtmlUListElement };
+  (video) => { $crate::web_sys::HtmlVideoElement };
+  (svg) => { $crate::web_sys::SvgsvgElement };
+  (circle) => { $crate::web_sys::SvgElement };
+  (clipPath) => { $crate::web_sys::SvgElement };
+  (defs) => { $crate::web_sys::SvgElement };
+  (ellipse) => { $crate::web_sys::SvgElement };
+  (foreignObject) => { $crate::web_sys::SvgElement };
+  (g) => { $crate::web_sys::SvgElement };
+  (line) => { $crate::web_sys::SvgElement };
+  (linearGradient) => { $crate::web_sys::SvgElement };
+  (marker) => { $crate::web_sys::SvgElement };
+  (mask) => { $crate::web_sys::SvgElement };
+  (path) => { $crate::web_sys::SvgElement };
+  (pattern) => { $crate::web_sys::SvgElement };
+  (polygon) => { $crate::web_sys::SvgElement };
+  (polyline) => { $crate::web_sys::SvgElement };
+  (radialGradient) => { $crate::web_sys::SvgElement };
+  (rect) => { $crate::web_sys::SvgElement };
+  (stop) => { $crate::web_sys::SvgElement };
+  (symbol) => { $crate::web_sys::SvgElement };
+  (text) => { $crate::web_sys::SvgElement };
+  (tspan) => { $crate::web_sys::SvgElement };
+  (abbr) => { $crate::web_sys::HtmlElement };
+  (address) => { $crate::web_sys::HtmlElement };
+  (article) => { $crate::web_sys::HtmlElement };
+  (aside) => { $crate::web_sys::HtmlElement };
+  (b) => { $crate::web_sys::HtmlElement };
+  (bdi) => { $crate::web_sys::HtmlElement };
+  (bdo) => { $crate::web_sys::HtmlElement };
+  (cite) => { $crate::web_sys::HtmlElement };
+  (code) => { $crate::web_sys::HtmlElement };
+  (dd) => { $crate::web_sys::HtmlElement };
+  (dfn) => { $crate::web_sys::HtmlElement };
+  (dt) => { $crate::web_sys::HtmlElement };
+  (em) => { $crate::web_sys::HtmlElement };
+  (figcaption) => { $crate::web_sys::HtmlElement };
+  (figure) => { $crate::web_sys::HtmlElement };
+  (footer) => { $crate::web_sys::HtmlElement };
+  (header) => { $crate::web_sys::HtmlElement };
+  (hgroup) => { $crate::web_sys::HtmlElement };
+  (i) => { $crate::web_sys::HtmlElement };
+  (kbd) => { $crate::web_sys::HtmlElement };
+  (main) => { $crate::web_sys::HtmlElement };
+  (mark) => { $crate::web_sys::HtmlElement };
+  (nav) => { $crate::web_sys::HtmlElement };
+  (noscript) => { $crate::web_sys::HtmlElement };
+  (rp) => { $crate::web_sys::HtmlElement };
+  (rt) => { $crate::web_sys::HtmlElement };
+  (ruby) => { $crate::web_sys::HtmlElement };
+  (s) => { $crate::web_sys::HtmlElement };
+  (samp) => { $crate::web_sys::HtmlElement };
+  (search) => { $crate::web_sys::HtmlElement };
+  (section) => { $crate::web_sys::HtmlElement };
+  (small) => { $crate::web_sys::HtmlElement };
+  (strong) => { $crate::web_sys::HtmlElement };
+  (sub) => { $crate::web_sys::HtmlElement };
+  (summary) => { $crate::web_sys::HtmlElement };
+  (sup) => { $crate::web_sys::HtmlElement };
+  (u) => { $crate::web_sys::HtmlElement };
+  (var) => { $crate::web_sys::HtmlElement };
+  (wbr) => { $crate::web_sys::HtmlElement };
+  // Unknown tags could be SVG elements or custom elements
+  ($tag:tt) => { $crate::web_sys::Element };
+}
+
+/// Returns the type of the DOM element with the given tag name, which is used
+/// by [`h!`](crate::h!) to check the types of refs.
+#[cfg(not(feature = "html-elements"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! html_element_type {
+  ($tag:tt) => { $crate::web_sys::Element };
+}
+
 /// A helper macro which can be used to clone a list of variables. Helpful for
 /// creating a closure which clone-captures the environment.
 ///
@@ -198,8 +380,10 @@ macro_rules! classnames {
 /// consumption via `wasm-bindgen`.
 ///
 /// Requirement is that you implement the [`TryFrom<JsValue, Error = JsValue>`](core::convert::TryFrom)
-/// trait on your component and that you do not export anything else that has
-/// the same name as your component.
+/// trait on your component, or derive [`Deserialize`](https://docs.rs/serde)
+/// with the `serde` feature enabled, and that you do not export anything else
+/// that has the same name as your component. If the props cannot be converted,
+/// the error is thrown to JS.
 ///
 /// Therefore, it is only recommended to use this macro if you're writing a
 /// library for JS consumption only, or if you're writing a standalone
@@ -255,6 +439,32 @@ macro_rules! classnames {
 /// }
 /// ```
 ///
+/// With the `serde` feature enabled, you can derive `Deserialize` instead. Props
+/// that do not match will throw a `TypeError` naming the component and field:
+///
+/// ```
+/// # use wasm_react::*;
+/// # use wasm_bindgen::prelude::*;
+/// # #[cfg(feature = "serde")]
+/// # mod m {
+/// # use wasm_react::*;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// pub struct Counter {
+///   counter: i32,
+///   label: Option<String>,
+/// }
+///
+/// impl Component for Counter {
+///   # fn render(&self) -> VNode { VNode::new() }
+///   /* … */
+/// }
+///
+/// export_components! { Counter }
+/// # }
+/// ```
+///
 /// You can export multiple components and also rename them:
 ///
 /// ```
@@ -277,6 +487,40 @@ macro_rules! classnames {
 ///   Counter
 /// }
 /// ```
+///
+/// Exported components can be wrapped in `React.forwardRef()` on the JS side.
+/// The forwarded ref will then be passed to the component as `ref` prop, so it
+/// can be read as [`JsRefContainer`](crate::hooks::JsRefContainer) and used
+/// with [`use_imperative_handle()`](crate::hooks::use_imperative_handle()).
+/// Only ref objects and ref callbacks are passed on, so components which are
+/// not wrapped won't receive a `ref` prop:
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// # use wasm_bindgen::prelude::*;
+/// # use js_sys::Reflect;
+/// pub struct Counter {
+///   handle: JsRefContainer<ImperativeHandle>,
+/// }
+///
+/// impl TryFrom<JsValue> for Counter {
+///   type Error = JsValue;
+///
+///   fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+///     Ok(Counter {
+///       handle: Reflect::get(&value, &"ref".into())?.into(),
+///     })
+///   }
+/// }
+/// #
+/// # impl Component for Counter { fn render(&self) -> VNode { VNode::new() } }
+///
+/// export_components! { Counter }
+/// ```
+///
+/// ```js
+/// const ForwardedCounter = React.forwardRef(Counter);
+/// ```
 #[macro_export]
 macro_rules! export_components {
   {} => {};
@@ -298,25 +542,50 @@ macro_rules! export_components {
       #[allow(non_snake_case)]
       #[allow(dead_code)]
       #[doc(hidden)]
-      #[::wasm_bindgen::prelude::wasm_bindgen(js_name = $Name)]
+      #[::wasm_bindgen::prelude::wasm_bindgen(js_name = $Name, skip_typescript)]
       pub fn [<__WasmReact_Export_ $Name>](
         props: ::wasm_bindgen::JsValue,
+        forwarded_ref: ::wasm_bindgen::JsValue,
       ) -> ::wasm_bindgen::JsValue
       where
         $Component: $crate::Component
-          + TryFrom<::wasm_bindgen::JsValue, Error = ::wasm_bindgen::JsValue>
       {
+        #[allow(unused_imports)]
+        use $crate::props::{
+          ConvertJsPropsWithSerde as _, ConvertJsPropsWithTryFrom as _,
+        };
+
         let component_ref = $crate::hooks::use_memo({
           let props = props.clone();
+          let forwarded_ref = forwarded_ref.clone();
 
-          move || $Component::try_from(props).unwrap()
-        }, $crate::hooks::Deps::some(props));
+          move || {
+            (&&$crate::props::JsPropsConversion::<$Component>::new())
+              .convert(
+                stringify!($Name),
+                $crate::props::with_forwarded_ref(props, forwarded_ref),
+              )
+          }
+        }, $crate::hooks::Deps::some((props, forwarded_ref)));
 
         $crate::react_bindings::use_rust_tmp_refs();
 
-        let component = component_ref.value();
-        $crate::Component::render(&*component).into()
+        let result = match &*component_ref.value() {
+          Ok(component) => Ok($crate::render_exported(component)),
+          Err(err) => Err(err.clone()),
+        };
+
+        // Throw outside of any borrows
+        result.unwrap_or_else(|err| ::wasm_bindgen::throw_val(err))
       }
+
+      #[allow(non_upper_case_globals)]
+      #[::wasm_bindgen::prelude::wasm_bindgen(typescript_custom_section)]
+      const [<__WASMREACT_EXPORT_TYPESCRIPT_ $Name>]: &'static str = concat!(
+        "export function ", stringify!($Name),
+        "(props: __WasmReactProps<\"", stringify!($Component), "\">, ",
+        "ref?: any): any;"
+      );
     }
 
     $( $crate::export_components! { $( $tail )* } )?
@@ -451,6 +720,8 @@ macro_rules! import_components {
           std::borrow::Cow::Borrowed(&[<__WASMREACT_IMPORT_ $Name:upper>])
         }
       }
+
+      impl<E> $crate::props::HRef<E> for $Name {}
     }
 
     $( $crate::import_components! { #[$from] $( $tail )* } )?
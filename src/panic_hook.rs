@@ -0,0 +1,120 @@
+//! Keeps track of Rust panics and the components that are rendering, so panics
+//! can be reported with the component in which they occurred.
+
+use std::{
+  cell::{Cell, RefCell},
+  fmt::Write,
+  panic,
+  sync::Once,
+};
+
+thread_local! {
+  static LAST_PANIC_MESSAGE: RefCell<Option<String>> =
+    const { RefCell::new(None) };
+  static COMPONENT_STACK: RefCell<Vec<&'static str>> =
+    const { RefCell::new(Vec::new()) };
+  static REPORT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that remembers the message of the last Rust panic,
+/// so error boundaries can retrieve it after the resulting wasm trap.
+pub(crate) fn install_panic_message_hook() {
+  static INSTALL: Once = Once::new();
+
+  INSTALL.call_once(|| {
+    let prev_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+      let payload = info.payload();
+      let message = payload
+        .downcast_ref::<&str>()
+        .map(|x| x.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned());
+
+      let components = COMPONENT_STACK.with(|stack| {
+        if cfg!(target_arch = "wasm32") {
+          // Rendering components won't be unwound after the wasm trap
+          stack.take()
+        } else {
+          stack.borrow().clone()
+        }
+      });
+
+      prev_hook(info);
+
+      if REPORT.with(|report| report.get()) {
+        let mut report = String::new();
+
+        if cfg!(target_arch = "wasm32") {
+          // The default panic hook doesn't print anything in the browser
+          let _ = write!(report, "{info}");
+        }
+
+        if let Some(component) = components.last() {
+          let _ = write!(
+            report,
+            "{}while rendering component `{component}`",
+            if report.is_empty() { "" } else { "\n\n" },
+          );
+
+          for parent in components.iter().rev().skip(1) {
+            let _ = write!(report, "\n    rendered by `{parent}`");
+          }
+        }
+
+        if !report.is_empty() {
+          if cfg!(target_arch = "wasm32") {
+            crate::react_bindings::console_error(&report);
+          } else {
+            eprintln!("{report}");
+          }
+        }
+      }
+
+      LAST_PANIC_MESSAGE.with(|last| *last.borrow_mut() = message);
+    }));
+  });
+}
+
+/// Installs the panic message hook and enables reporting panics together with
+/// the rendering components to the console.
+pub(crate) fn install_reporting_panic_hook() {
+  REPORT.with(|report| report.set(true));
+  install_panic_message_hook();
+}
+
+/// Returns the message of the last Rust panic, if it hasn't been retrieved
+/// yet.
+pub(crate) fn take_last_panic_message() -> Option<String> {
+  LAST_PANIC_MESSAGE.with(|last| last.borrow_mut().take())
+}
+
+/// Pops the component from the component stack even if rendering panics.
+struct ComponentGuard;
+
+impl Drop for ComponentGuard {
+  fn drop(&mut self) {
+    COMPONENT_STACK.with(|stack| stack.borrow_mut().pop());
+  }
+}
+
+/// Calls the given closure while the component with the given name is marked
+/// as rendering.
+pub(crate) fn with_component<R>(
+  name: &'static str,
+  f: impl FnOnce() -> R,
+) -> R {
+  COMPONENT_STACK.with(|stack| stack.borrow_mut().push(name));
+
+  let _guard = ComponentGuard;
+  f()
+}
+
+/// Removes all components from the component stack.
+///
+/// React never renders components in a nested fashion, so entries in the stack
+/// at the beginning of a render have been left over by renders that have been
+/// aborted by JS exceptions, which don't run destructors.
+pub(crate) fn reset_component_stack() {
+  COMPONENT_STACK.with(|stack| stack.borrow_mut().clear());
+}
@@ -1,4 +1,7 @@
-use crate::hooks::{DeferredValue, Memo, RefContainer, State};
+use crate::{
+  hooks::{DeferredValue, Memo, RefContainer, State, SyncExternalStore},
+  Signal,
+};
 use std::{
   cell::{Ref, RefCell},
   ops::Deref,
@@ -45,7 +48,10 @@ macro_rules! define_value_container {
     /// A helpful abstraction over non-`Copy` types that can be used as a prop
     /// type for components.
     ///
-    /// Can contain all hook containers, [`Rc<T>`], and [`Rc<RefCell<T>>`].
+    /// Can contain all hook containers, [`Signal<T>`], [`Rc<T>`], and
+    /// [`Rc<RefCell<T>>`]. Reading a signal through a [`PropContainer`] won't
+    /// subscribe the component to it, use
+    /// [`use_signal_value()`](crate::hooks::use_signal_value()) for that.
     #[non_exhaustive]
     #[derive(Debug)]
     pub enum PropContainer<T> {
@@ -89,11 +95,19 @@ define_value_container! {
   State(x: State<T>) => Ref(x.value()),
   Memo(x: Memo<T>) => Ref(x.value()),
   DeferredValue(x: DeferredValue<T>) => Ref(x.value()),
+  SyncExternalStore(x: SyncExternalStore<T>) => Ref(x.value()),
+  Signal(x: Signal<T>) => Ref(x.get()),
 }
 
 impl<T: PartialEq + 'static> PartialEq for PropContainer<T> {
   fn eq(&self, other: &Self) -> bool {
-    T::eq(&self.value(), &other.value())
+    match (self, other) {
+      // Components subscribe to signals, so a different signal with the same
+      // value still needs a rerender
+      (Self::Signal(x), Self::Signal(y)) => x == y,
+      (Self::Signal(_), _) | (_, Self::Signal(_)) => false,
+      _ => T::eq(&self.value(), &other.value()),
+    }
   }
 }
 
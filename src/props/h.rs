@@ -1,19 +1,43 @@
-use super::Props;
-use crate::{
-  Callback, create_element, hooks::JsRefContainer, KeyType, VNode,
-};
-use std::borrow::Cow;
+use super::{PropValue, Props};
+#[cfg(not(native_runtime))]
+use crate::create_element;
+#[cfg(native_runtime)]
+use crate::ssr::{ElementType, Node};
+use crate::{Callback, hooks::JsRefContainer, KeyType, VNode};
+use std::{borrow::Cow, fmt::Debug, marker::PhantomData};
 use wasm_bindgen::{
-  convert::{FromWasmAbi, IntoWasmAbi},
+  convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi},
   intern, JsValue,
 };
 use web_sys::Element;
 
+/// The component type of HTML elements with the tag name and the type `E` of
+/// the DOM element, which is determined by [`h!`](crate::h!).
 #[doc(hidden)]
-#[derive(Debug, Clone, Copy)]
-pub struct HtmlTag<'a>(pub &'a str);
+pub struct HtmlTag<'a, E = Element>(pub &'a str, pub PhantomData<E>);
+
+impl<'a, E> HtmlTag<'a, E> {
+  /// Creates a new HTML component type with the given tag name.
+  pub fn new(tag: &'a str) -> Self {
+    Self(tag, PhantomData)
+  }
+}
+
+impl<E> Debug for HtmlTag<'_, E> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("HtmlTag").field(&self.0).finish()
+  }
+}
+
+impl<E> Clone for HtmlTag<'_, E> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<E> Copy for HtmlTag<'_, E> {}
 
-impl AsRef<str> for HtmlTag<'_> {
+impl<E> AsRef<str> for HtmlTag<'_, E> {
   fn as_ref(&self) -> &str {
     &self.0
   }
@@ -25,14 +49,39 @@ impl AsRef<str> for HtmlTag<'_> {
 pub trait HType {
   /// Returns a reference to the [`JsValue`] of this component type.
   fn as_js(&self) -> Cow<'_, JsValue>;
+
+  /// Returns how the component type is rendered on the server.
+  #[doc(hidden)]
+  #[cfg(native_runtime)]
+  fn element_type(&self) -> ElementType<'_> {
+    ElementType::Unsupported
+  }
 }
 
-impl HType for HtmlTag<'_> {
+impl<E> HType for HtmlTag<'_, E> {
   fn as_js(&self) -> Cow<'_, JsValue> {
     Cow::Owned(intern(self.0).into())
   }
+
+  #[cfg(native_runtime)]
+  fn element_type(&self) -> ElementType<'_> {
+    ElementType::Html(self.0)
+  }
 }
 
+/// A marker trait for component types which accept refs to values of type `E`,
+/// used by [`H::ref_container_typed()`] and [`H::ref_callback_typed()`].
+///
+/// HTML elements built with [`h!`](crate::h!) accept refs to their DOM element
+/// type and all of its base types, e.g. `h!(input)` accepts refs to
+/// `HtmlInputElement`, `HtmlElement` and `Element` with the `html-elements`
+/// feature enabled, otherwise refs to `Element`. SVG elements and unknown tags
+/// accept refs to `SvgElement` or `Element` respectively. Imported components
+/// accept refs of any type.
+pub trait HRef<E> {}
+
+impl<E, R> HRef<R> for HtmlTag<'_, E> where E: AsRef<R> {}
+
 /// The component builder that powers [`h!`](crate::h!), which provides
 /// convenience methods for adding props.
 ///
@@ -65,6 +114,8 @@ impl<T: HType> H<T> {
   /// Sets the [React ref][ref] to the given ref container created with the
   /// [`use_js_ref()`](crate::hooks::use_js_ref()) hook.
   ///
+  /// Use [`H::ref_container_typed()`] for refs of other types.
+  ///
   /// [ref]: https://react.dev/learn/manipulating-the-dom-with-refs
   pub fn ref_container(
     mut self,
@@ -76,6 +127,8 @@ impl<T: HType> H<T> {
 
   /// Sets the [React ref][ref] to the given ref callback.
   ///
+  /// Use [`H::ref_callback_typed()`] for refs of other types.
+  ///
   /// [ref]: https://react.dev/learn/manipulating-the-dom-with-refs
   pub fn ref_callback(
     mut self,
@@ -85,8 +138,35 @@ impl<T: HType> H<T> {
     self
   }
 
+  /// Same as [`H::ref_container()`], but the ref can have any type accepted by
+  /// the component type, see [`HRef`], e.g. the type of the DOM element.
+  pub fn ref_container_typed<E>(
+    mut self,
+    ref_container: &JsRefContainer<E>,
+  ) -> Self
+  where
+    T: HRef<E>,
+  {
+    self.props = self.props.ref_container(ref_container);
+    self
+  }
+
+  /// Same as [`H::ref_callback()`], but the ref can have any type accepted by
+  /// the component type, see [`HRef`], e.g. the type of the DOM element.
+  pub fn ref_callback_typed<E>(
+    mut self,
+    ref_callback: &Callback<Option<E>>,
+  ) -> Self
+  where
+    T: HRef<E>,
+    E: OptionFromWasmAbi + 'static,
+  {
+    self.props = self.props.ref_callback(ref_callback);
+    self
+  }
+
   /// Sets an attribute on the [`VNode`].
-  pub fn attr(mut self, key: &str, value: &JsValue) -> Self {
+  pub fn attr(mut self, key: &str, value: &PropValue) -> Self {
     self.props = self.props.insert(key, value);
     self
   }
@@ -103,6 +183,17 @@ impl<T: HType> H<T> {
 
   /// Builds the [`VNode`] and returns it with the given children.
   pub fn build(self, children: impl Into<VNode>) -> VNode {
-    create_element(&self.typ.as_js(), &self.props, children.into())
+    #[cfg(not(native_runtime))]
+    {
+      create_element(&self.typ.as_js(), &self.props, children.into())
+    }
+    #[cfg(native_runtime)]
+    {
+      VNode::Native(Node::element(
+        self.typ.element_type(),
+        self.props,
+        children.into(),
+      ))
+    }
   }
 }
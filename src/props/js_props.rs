@@ -0,0 +1,141 @@
+use std::marker::PhantomData;
+use wasm_bindgen::{intern, JsCast, JsValue, UnwrapThrowExt};
+
+/// Converts the JS props object of a component into the given type with
+/// [`serde`](https://serde.rs).
+///
+/// In contrast to [`serde_wasm_bindgen::from_value()`], the returned error is a
+/// JS `TypeError` with a readable message which names the component and the
+/// offending field.
+///
+/// Requires the `serde` feature. Components exported with
+/// [`export_components!`](crate::export_components) use this function
+/// automatically if they implement [`Deserialize`](serde::Deserialize), so you
+/// only need to call it yourself if you implement [`TryFrom<JsValue>`] by hand.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, props::*};
+/// # use wasm_bindgen::prelude::*;
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct CounterProps {
+///   counter: i32,
+/// }
+///
+/// pub struct Counter {
+///   counter: i32,
+/// }
+///
+/// impl TryFrom<JsValue> for Counter {
+///   type Error = JsValue;
+///
+///   fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+///     let props: CounterProps = from_js_props("Counter", value)?;
+///
+///     Ok(Counter { counter: props.counter })
+///   }
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn from_js_props<T>(component: &str, value: JsValue) -> Result<T, JsValue>
+where
+  T: serde::de::DeserializeOwned,
+{
+  let deserializer = serde_wasm_bindgen::Deserializer::from(value);
+
+  serde_path_to_error::deserialize(deserializer).map_err(|err| {
+    let path = err.path().to_string();
+    let message = if path == "." {
+      format!("Invalid props for component `{component}`: {}", err.inner())
+    } else {
+      format!(
+        "Invalid prop `{path}` for component `{component}`: {}",
+        err.inner()
+      )
+    };
+
+    js_sys::TypeError::new(&message).into()
+  })
+}
+
+/// Used by [`export_components!`](crate::export_components) to add the ref
+/// forwarded by `React.forwardRef()` to the props as `ref` prop.
+///
+/// Only ref callbacks and ref objects are forwarded, since React 18 calls
+/// plain function components with the legacy context object as second
+/// argument instead.
+#[doc(hidden)]
+pub fn with_forwarded_ref(props: JsValue, forwarded_ref: JsValue) -> JsValue {
+  let is_ref = forwarded_ref.is_function()
+    || (forwarded_ref.is_object()
+      && js_sys::Reflect::has(
+        forwarded_ref.unchecked_ref(),
+        &intern("current").into(),
+      )
+      .unwrap_or(false));
+
+  if !is_ref {
+    return props;
+  }
+
+  let result = js_sys::Object::new();
+
+  if props.is_object() {
+    js_sys::Object::assign(&result, props.unchecked_ref());
+  }
+
+  js_sys::Reflect::set(&result, &intern("ref").into(), &forwarded_ref)
+    .expect_throw("cannot write into props object");
+
+  result.into()
+}
+
+/// Used by [`export_components!`](crate::export_components) to pick the props
+/// conversion of a component via autoref specialization: Components that
+/// implement [`TryFrom<JsValue>`] are converted with it, otherwise
+/// [`from_js_props()`] is used if the `serde` feature is enabled.
+#[doc(hidden)]
+pub struct JsPropsConversion<T>(PhantomData<T>);
+
+impl<T> JsPropsConversion<T> {
+  pub fn new() -> Self {
+    Self(PhantomData)
+  }
+}
+
+impl<T> Default for JsPropsConversion<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[doc(hidden)]
+pub trait ConvertJsPropsWithTryFrom<T> {
+  fn convert(&self, component: &str, value: JsValue) -> Result<T, JsValue>;
+}
+
+impl<T> ConvertJsPropsWithTryFrom<T> for &JsPropsConversion<T>
+where
+  T: TryFrom<JsValue, Error = JsValue>,
+{
+  fn convert(&self, _: &str, value: JsValue) -> Result<T, JsValue> {
+    T::try_from(value)
+  }
+}
+
+#[doc(hidden)]
+pub trait ConvertJsPropsWithSerde<T> {
+  fn convert(&self, component: &str, value: JsValue) -> Result<T, JsValue>;
+}
+
+#[cfg(feature = "serde")]
+impl<T> ConvertJsPropsWithSerde<T> for JsPropsConversion<T>
+where
+  T: serde::de::DeserializeOwned,
+{
+  fn convert(&self, component: &str, value: JsValue) -> Result<T, JsValue> {
+    from_js_props(component, value)
+  }
+}
@@ -4,6 +4,7 @@
 mod h_attrs;
 mod classnames;
 mod h_events;
+mod js_props;
 mod props;
 mod h;
 mod style;
@@ -12,5 +13,13 @@ pub use h::*;
 pub use h_attrs::*;
 pub use classnames::*;
 pub use h_events::*;
+pub use js_props::*;
 pub use props::*;
 pub use style::*;
+
+/// The type of prop values, which is [`JsValue`](wasm_bindgen::JsValue) unless
+/// the `native-runtime` feature is enabled on a target without JS.
+#[cfg(not(native_runtime))]
+pub type PropValue = wasm_bindgen::JsValue;
+#[cfg(native_runtime)]
+pub use crate::ssr::PropValue;
@@ -1,12 +1,19 @@
-use crate::{
-  hooks::{use_tmp_ref, JsRefContainer},
-  Callback, KeyType,
-};
+use super::PropValue;
+#[cfg(not(native_runtime))]
+use crate::hooks::use_tmp_ref;
+#[cfg(native_runtime)]
+use crate::ssr;
+use crate::{hooks::JsRefContainer, Callback, KeyType};
+#[cfg(native_runtime)]
+use js_sys::Object;
+#[cfg(not(native_runtime))]
 use js_sys::{Object, Reflect};
-use wasm_bindgen::{
-  convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi},
-  intern, JsCast, JsValue, UnwrapThrowExt,
-};
+#[cfg(native_runtime)]
+use std::rc::Rc;
+use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi};
+use wasm_bindgen::JsValue;
+#[cfg(not(native_runtime))]
+use wasm_bindgen::{intern, JsCast, UnwrapThrowExt};
 
 /// A convenience builder for JS objects. Mainly used for constructing props
 /// that are not controlled by Rust.
@@ -27,7 +34,11 @@ use wasm_bindgen::{
 /// # }
 /// ```
 #[derive(Debug, Default, Clone)]
-pub struct Props(Object);
+#[cfg_attr(native_runtime, derive(PartialEq))]
+pub struct Props(
+  #[cfg(not(native_runtime))] Object,
+  #[cfg(native_runtime)] Vec<(Rc<str>, PropValue)>,
+);
 
 impl Props {
   /// Creates a new, empty object.
@@ -39,10 +50,7 @@ impl Props {
   ///
   /// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
   pub fn key(self, value: Option<impl KeyType>) -> Self {
-    self.insert(
-      "key",
-      &value.map(|x| x.into()).unwrap_or(JsValue::UNDEFINED),
-    )
+    self.insert("key", &value.map(|x| x.into()).unwrap_or_default())
   }
 
   /// Sets the [React ref][ref] to the given ref container created with the
@@ -50,7 +58,16 @@ impl Props {
   ///
   /// [ref]: https://react.dev/learn/manipulating-the-dom-with-refs
   pub fn ref_container<T>(self, ref_container: &JsRefContainer<T>) -> Self {
-    self.insert("ref", ref_container.as_ref())
+    #[cfg(not(native_runtime))]
+    {
+      self.insert("ref", ref_container.as_ref())
+    }
+    #[cfg(native_runtime)]
+    {
+      // Refs are never attached on the server
+      let _ = ref_container;
+      self
+    }
   }
 
   /// Sets the [React ref][ref] to the given ref callback.
@@ -60,49 +77,113 @@ impl Props {
   where
     T: OptionFromWasmAbi + 'static,
   {
-    self.insert_callback("ref", ref_callback)
+    #[cfg(not(native_runtime))]
+    {
+      self.insert_callback("ref", ref_callback)
+    }
+    #[cfg(native_runtime)]
+    {
+      // Refs are never attached on the server
+      let _ = ref_callback;
+      self
+    }
   }
 
   /// Equivalent to `props[key] = value;`.
-  pub fn insert(self, key: &str, value: &JsValue) -> Self {
+  pub fn insert(mut self, key: &str, value: &PropValue) -> Self {
     self.ref_insert(key, value);
     self
   }
 
-  fn ref_insert(&self, key: &str, value: &JsValue) {
+  #[cfg(not(native_runtime))]
+  fn ref_insert(&mut self, key: &str, value: &JsValue) {
     Reflect::set(&self.0, &intern(key).into(), value)
       .expect_throw("cannot write into props object");
   }
 
+  #[cfg(native_runtime)]
+  fn ref_insert(&mut self, key: &str, value: &PropValue) {
+    match self.0.iter_mut().find(|(k, _)| &**k == key) {
+      Some((_, v)) => *v = value.clone(),
+      None => self.0.push((key.into(), value.clone())),
+    }
+  }
+
   /// Equivalent to `props[key] = f;`.
-  pub fn insert_callback<T, U>(self, key: &str, f: &Callback<T, U>) -> Self
+  pub fn insert_callback<T, U>(mut self, key: &str, f: &Callback<T, U>) -> Self
   where
     T: FromWasmAbi + 'static,
     U: IntoWasmAbi + 'static,
   {
+    #[cfg(not(native_runtime))]
     use_tmp_ref(f.clone(), |f| {
       self.ref_insert(key, &f.as_js());
     });
 
+    #[cfg(native_runtime)]
+    self.ref_insert(key, &PropValue::Function(Rc::new(f.clone())));
+
     self
   }
+
+  /// Returns the value of the given key.
+  #[cfg(native_runtime)]
+  pub(crate) fn get(&self, key: &str) -> Option<&PropValue> {
+    self.0.iter().find(|(k, _)| &**k == key).map(|(_, v)| v)
+  }
+
+  /// Returns an iterator over all entries in insertion order.
+  #[cfg(native_runtime)]
+  pub(crate) fn iter(&self) -> impl Iterator<Item = (&str, &PropValue)> {
+    self.0.iter().map(|(k, v)| (&**k, v))
+  }
 }
 
 impl AsRef<JsValue> for Props {
   fn as_ref(&self) -> &JsValue {
-    &self.0
+    #[cfg(not(native_runtime))]
+    {
+      &self.0
+    }
+    #[cfg(native_runtime)]
+    {
+      ssr::unsupported("converting props into JS values")
+    }
   }
 }
 
 impl From<Props> for JsValue {
-  fn from(style: Props) -> Self {
-    style.0.into()
+  fn from(props: Props) -> Self {
+    #[cfg(not(native_runtime))]
+    {
+      props.0.into()
+    }
+    #[cfg(native_runtime)]
+    {
+      let _ = props;
+      ssr::unsupported("converting props into JS values")
+    }
+  }
+}
+
+#[cfg(native_runtime)]
+impl From<Props> for PropValue {
+  fn from(props: Props) -> Self {
+    PropValue::Object(props)
   }
 }
 
 impl From<Object> for Props {
   fn from(value: Object) -> Self {
-    Props(value)
+    #[cfg(not(native_runtime))]
+    {
+      Props(value)
+    }
+    #[cfg(native_runtime)]
+    {
+      let _ = value;
+      ssr::unsupported("reading props from JS objects")
+    }
   }
 }
 
@@ -110,6 +191,14 @@ impl TryFrom<JsValue> for Props {
   type Error = JsValue;
 
   fn try_from(value: JsValue) -> Result<Self, Self::Error> {
-    Ok(Props(value.dyn_into::<Object>()?))
+    #[cfg(not(native_runtime))]
+    {
+      Ok(Props(value.dyn_into::<Object>()?))
+    }
+    #[cfg(native_runtime)]
+    {
+      // JS objects can't be read without the React runtime
+      Err(value)
+    }
   }
 }
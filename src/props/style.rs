@@ -1,4 +1,4 @@
-use super::Props;
+use super::{PropValue, Props};
 use wasm_bindgen::{intern, JsValue};
 
 /// A convenience wrapper around [`Props`] that provides auto-completion for
@@ -24,7 +24,7 @@ impl Style {
   }
 
   /// Equivalent to `props[key] = value;`.
-  pub fn insert(self, key: &str, value: &JsValue) -> Self {
+  pub fn insert(self, key: &str, value: &PropValue) -> Self {
     Self(self.0.insert(key, value))
   }
 }
@@ -41,11 +41,18 @@ impl From<Style> for JsValue {
   }
 }
 
+#[cfg(native_runtime)]
+impl From<Style> for PropValue {
+  fn from(style: Style) -> Self {
+    style.0.into()
+  }
+}
+
 macro_rules! impl_style {
   { $( $attr:ident, $attr_str:literal; )* } => {
     $(
       #[allow(missing_docs)]
-      pub fn $attr(self, value: impl Into<JsValue>) -> Self {
+      pub fn $attr(self, value: impl Into<PropValue>) -> Self {
         self.insert(intern($attr_str), &value.into())
       }
     )*
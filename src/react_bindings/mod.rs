@@ -52,12 +52,27 @@ extern "C" {
   #[wasm_bindgen(js_name = useRustLayoutEffect)]
   pub fn use_rust_layout_effect(effect: &JsValue, dep: u8);
 
+  #[wasm_bindgen(js_name = useRustInsertionEffect)]
+  pub fn use_rust_insertion_effect(effect: &JsValue, dep: u8);
+
   #[wasm_bindgen(js_name = useRustContext)]
   pub fn use_rust_context(
     context: &JsValue,
     callback: &mut dyn FnMut(&RefContainerValue),
   );
 
+  #[wasm_bindgen(js_name = useRustOptimistic)]
+  pub fn use_rust_optimistic() -> Array;
+
+  #[wasm_bindgen(js_name = useRustActionState)]
+  pub fn use_rust_action_state(run: &JsValue) -> Array;
+
+  #[wasm_bindgen(js_name = getErrorBoundary)]
+  pub fn get_error_boundary() -> JsValue;
+
+  #[wasm_bindgen(js_name = createLazy)]
+  pub fn create_lazy(load: &JsValue) -> JsValue;
+
   // From the React namespace:
 
   #[wasm_bindgen(js_namespace = React, js_name = Fragment)]
@@ -69,6 +84,9 @@ extern "C" {
   #[wasm_bindgen(js_namespace = React, js_name = useRef)]
   pub fn use_ref(init: &JsValue) -> JsValue;
 
+  #[wasm_bindgen(js_namespace = React, js_name = useDebugValue)]
+  pub fn use_debug_value(value: &JsValue);
+
   #[wasm_bindgen(js_namespace = React, js_name = useId)]
   pub fn use_id() -> String;
 
@@ -78,6 +96,56 @@ extern "C" {
   #[wasm_bindgen(js_namespace = React, js_name = useTransition)]
   pub fn use_transition() -> Array;
 
+  #[wasm_bindgen(js_namespace = React, js_name = startTransition)]
+  pub fn start_transition(scope: &JsValue);
+
+  #[wasm_bindgen(js_namespace = React, js_name = useSyncExternalStore)]
+  pub fn use_sync_external_store(
+    subscribe: &JsValue,
+    get_snapshot: &JsValue,
+    get_server_snapshot: &JsValue,
+  ) -> u8;
+
   #[wasm_bindgen(js_namespace = React, js_name = createContext)]
   pub fn create_context(value: RefContainerValue) -> JsValue;
 }
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(js_namespace = console, js_name = error)]
+  pub fn console_error(message: &str);
+}
+
+#[cfg(feature = "react-dom")]
+#[wasm_bindgen(module = "/src/react_bindings/react-bindings.js")]
+extern "C" {
+  #[wasm_bindgen(js_name = useReactDom)]
+  pub fn use_react_dom(value: &JsValue);
+
+  #[derive(Debug, Clone)]
+  pub type ReactRoot;
+
+  #[wasm_bindgen(method)]
+  pub fn render(this: &ReactRoot, children: &JsValue);
+
+  #[wasm_bindgen(method)]
+  pub fn unmount(this: &ReactRoot);
+
+  // From the ReactDOM namespace:
+
+  #[wasm_bindgen(js_namespace = ReactDOM, js_name = createRoot)]
+  pub fn create_root(container: &web_sys::Element) -> ReactRoot;
+
+  #[wasm_bindgen(js_namespace = ReactDOM, js_name = hydrateRoot)]
+  pub fn hydrate_root(
+    container: &web_sys::Element,
+    children: &JsValue,
+  ) -> ReactRoot;
+
+  #[wasm_bindgen(js_namespace = ReactDOM, js_name = createPortal)]
+  pub fn create_portal(
+    children: &JsValue,
+    container: &web_sys::Element,
+    key: &JsValue,
+  ) -> JsValue;
+}
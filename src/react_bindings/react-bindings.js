@@ -1,6 +1,7 @@
 const components = {};
 
 export let React = undefined;
+export let ReactDOM = undefined;
 
 export function useReact(value) {
   if (React == null) {
@@ -8,6 +9,12 @@ export function useReact(value) {
   }
 }
 
+export function useReactDom(value) {
+  if (ReactDOM == null) {
+    ReactDOM = value;
+  }
+}
+
 export function createElement(name, props, children) {
   if (!Array.isArray(children)) children = [children];
   return React.createElement(name, props, ...children);
@@ -146,6 +153,86 @@ export function useRustLayoutEffect(effect, dep) {
   React.useLayoutEffect(effect, [dep]);
 }
 
+export function useRustInsertionEffect(effect, dep) {
+  React.useInsertionEffect(effect, [dep]);
+}
+
 export function useRustContext(context, callback) {
   callback(React.useContext(context));
 }
+
+const noOptimisticUpdates = [];
+
+export function useRustOptimistic() {
+  // Optimistic updates are stored in Rust, React only keeps track of their ids
+  return React.useOptimistic(noOptimisticUpdates, (ids, id) => [...ids, id]);
+}
+
+export function useRustActionState(run) {
+  // The state is stored in Rust, `run` calls the action with the next queued
+  // payload and returns a promise if the action is async
+  let [, dispatch, isPending] = React.useActionState(() => run(), null);
+
+  let dispatchInTransition = React.useCallback(
+    () => React.startTransition(() => dispatch()),
+    [dispatch]
+  );
+
+  return [dispatchInTransition, isPending];
+}
+
+function renderLazyComponent(props) {
+  return props.render();
+}
+
+export function createLazy(load) {
+  return React.lazy(() =>
+    load().then(() => ({ default: renderLazyComponent }))
+  );
+}
+
+let ErrorBoundary = null;
+const errorBoundaryInitialState = {
+  caught: false,
+  error: undefined,
+  componentStack: null,
+};
+
+export function getErrorBoundary() {
+  if (ErrorBoundary == null) {
+    // React only supports error boundaries as class components
+    ErrorBoundary = class extends React.Component {
+      constructor(props) {
+        super(props);
+
+        this.state = errorBoundaryInitialState;
+        this.reset = () => this.setState(errorBoundaryInitialState);
+      }
+
+      static getDerivedStateFromError(error) {
+        return { caught: true, error };
+      }
+
+      componentDidCatch(_, info) {
+        // The component stack is only available after the fallback has been
+        // rendered for the first time
+        this.setState({ componentStack: info.componentStack ?? null });
+      }
+
+      render() {
+        if (!this.state.caught) return this.props.children;
+        if (this.props.fallback == null) return null;
+
+        return this.props.fallback([
+          this.state.error,
+          this.reset,
+          this.state.componentStack,
+        ]);
+      }
+    };
+
+    ErrorBoundary.displayName = "ErrorBoundary";
+  }
+
+  return ErrorBoundary;
+}
@@ -0,0 +1,143 @@
+use crate::react_bindings;
+use crate::{props::PropValue, KeyType, VNode};
+#[cfg(not(native_runtime))]
+use wasm_bindgen::JsValue;
+use web_sys::Element;
+
+/// Represents a [React root][root] which displays React components inside a
+/// browser DOM node.
+///
+/// Requires the `react-dom` feature and a ReactDOM runtime registered with
+/// `WasmReact.useReactDom()`.
+///
+/// [root]: https://react.dev/reference/react-dom/client/createRoot
+///
+/// # Example
+///
+/// ```no_run
+/// # use wasm_react::*;
+/// # use wasm_bindgen::prelude::*;
+/// # use web_sys::Element;
+/// # struct App;
+/// # impl Component for App {
+/// #   fn render(&self) -> VNode { VNode::new() }
+/// # }
+/// fn mount(container: &Element) -> Result<(), JsValue> {
+///   // Use the runtimes loaded as global scripts
+///   let global = js_sys::global();
+///   WasmReact::use_react(&js_sys::Reflect::get(&global, &"React".into())?);
+///   WasmReact::use_react_dom(&js_sys::Reflect::get(&global, &"ReactDOM".into())?);
+///
+///   Root::create(container).render(App.build());
+///   Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Root(react_bindings::ReactRoot);
+
+impl Root {
+  /// Creates a new React root for the given container.
+  ///
+  /// See [React documentation](https://react.dev/reference/react-dom/client/createRoot).
+  pub fn create(container: &Element) -> Self {
+    Self(react_bindings::create_root(container))
+  }
+
+  /// Displays the given node inside the root, replacing previously rendered
+  /// content.
+  pub fn render(&self, children: impl Into<VNode>) {
+    self.0.render(&children.into().into());
+  }
+
+  /// Destroys the rendered tree inside the root.
+  pub fn unmount(self) {
+    self.0.unmount();
+  }
+}
+
+/// Attaches React to server-rendered HTML inside the given container and
+/// returns the created [`Root`].
+///
+/// See [React documentation](https://react.dev/reference/react-dom/client/hydrateRoot).
+pub fn hydrate_root(container: &Element, children: impl Into<VNode>) -> Root {
+  Root(react_bindings::hydrate_root(
+    container,
+    &children.into().into(),
+  ))
+}
+
+/// A builder for [portals][portal] which render their children into a
+/// different DOM node outside of the parent component.
+///
+/// Requires the `react-dom` feature. See [`create_portal()`] for usage.
+///
+/// [portal]: https://react.dev/reference/react-dom/createPortal
+#[derive(Debug, Clone)]
+pub struct Portal {
+  container: Element,
+  key: Option<PropValue>,
+}
+
+impl Portal {
+  /// Creates a new portal builder which renders into the given container.
+  pub fn new(container: &Element) -> Self {
+    Self {
+      container: container.clone(),
+      key: None,
+    }
+  }
+
+  /// Sets the [React key][key].
+  ///
+  /// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
+  pub fn key(mut self, key: Option<impl KeyType>) -> Self {
+    self.key = key.map(|x| x.into());
+    self
+  }
+
+  /// Returns a [`VNode`] to be included in a render function.
+  pub fn build(self, children: impl Into<VNode>) -> VNode {
+    #[cfg(not(native_runtime))]
+    {
+      VNode::Single(react_bindings::create_portal(
+        &children.into().into(),
+        &self.container,
+        &self.key.unwrap_or(JsValue::UNDEFINED),
+      ))
+    }
+    #[cfg(native_runtime)]
+    {
+      // Like React, portals can't be rendered without a DOM
+      let _ = (self.container, self.key, children);
+      crate::ssr::unsupported("rendering portals")
+    }
+  }
+}
+
+/// Renders the given children into a different DOM node. Use [`Portal`] if you
+/// need to specify a [React key][key].
+///
+/// Requires the `react-dom` feature.
+///
+/// [key]: https://react.dev/learn/rendering-lists#keeping-list-items-in-order-with-key
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::*;
+/// # use web_sys::Element;
+/// # struct Modal { body: Element }
+/// impl Component for Modal {
+///   fn render(&self) -> VNode {
+///     h!(div[."modal-anchor"]).build(
+///       create_portal(
+///         h!(div[."modal"]).build("This is rendered into the body."),
+///         &self.body,
+///       )
+///     )
+///   }
+/// }
+/// ```
+pub fn create_portal(children: impl Into<VNode>, container: &Element) -> VNode {
+  Portal::new(container).build(children)
+}
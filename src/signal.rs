@@ -0,0 +1,361 @@
+use std::{
+  cell::{Cell, Ref, RefCell},
+  fmt::Debug,
+  rc::{Rc, Weak},
+};
+use wasm_bindgen::UnwrapThrowExt;
+
+type Listener = Rc<dyn Fn()>;
+
+/// A signal which can be read by computed signals.
+trait Source {
+  fn subscribe(&self, listener: Listener) -> u64;
+  fn unsubscribe(&self, id: u64);
+}
+
+thread_local! {
+  /// Collects the signals read by the computed signals which are currently
+  /// being computed.
+  static TRACKING: RefCell<Vec<Vec<Rc<dyn Source>>>> =
+    const { RefCell::new(Vec::new()) };
+}
+
+/// Stops collecting the read signals even if the computation panics.
+struct TrackingGuard;
+
+impl TrackingGuard {
+  fn new() -> Self {
+    TRACKING.with(|tracking| tracking.borrow_mut().push(Vec::new()));
+    TrackingGuard
+  }
+
+  fn finish(self) -> Vec<Rc<dyn Source>> {
+    TRACKING
+      .with(|tracking| tracking.borrow_mut().last_mut().map(std::mem::take))
+      .unwrap_or_default()
+  }
+}
+
+impl Drop for TrackingGuard {
+  fn drop(&mut self) {
+    TRACKING.with(|tracking| tracking.borrow_mut().pop());
+  }
+}
+
+struct Computation<T> {
+  compute: Box<dyn Fn() -> T>,
+  eq: fn(&T, &T) -> bool,
+  dirty: Cell<bool>,
+  sources: RefCell<Vec<(Rc<dyn Source>, u64)>>,
+}
+
+struct SignalInner<T> {
+  value: RefCell<Option<T>>,
+  /// Incremented whenever the value has changed.
+  version: Cell<u64>,
+  next_id: Cell<u64>,
+  listeners: RefCell<Vec<(u64, Listener)>>,
+  computation: Option<Computation<T>>,
+}
+
+impl<T> SignalInner<T> {
+  fn notify(&self) {
+    self.version.set(self.version.get().wrapping_add(1));
+
+    // Listeners might subscribe or unsubscribe while being called
+    let listeners = self
+      .listeners
+      .borrow()
+      .iter()
+      .map(|(_, listener)| listener.clone())
+      .collect::<Vec<_>>();
+
+    for listener in listeners {
+      listener();
+    }
+  }
+}
+
+impl<T: 'static> SignalInner<T> {
+  /// Recomputes a computed signal after one of its sources has changed and
+  /// notifies its listeners if its value has changed as well.
+  fn invalidate(self: &Rc<Self>) {
+    let Some(computation) = &self.computation else {
+      return;
+    };
+
+    if self.listeners.borrow().is_empty() {
+      // Nobody observes the signal, so we compute it once it's read
+      computation.dirty.set(true);
+    } else if self.recompute(computation) {
+      self.notify();
+    }
+  }
+
+  /// Computes the value of a computed signal, tracks the signals it reads, and
+  /// returns whether the value has changed.
+  fn recompute(self: &Rc<Self>, computation: &Computation<T>) -> bool {
+    for (source, id) in computation.sources.take() {
+      source.unsubscribe(id);
+    }
+
+    let guard = TrackingGuard::new();
+    let value = (computation.compute)();
+    let mut sources = guard.finish();
+
+    sources.dedup_by(|x, y| Rc::ptr_eq(x, y));
+    computation.dirty.set(false);
+
+    let changed = {
+      let mut current = self.value.borrow_mut();
+      let changed = current
+        .as_ref()
+        .is_none_or(|current| !(computation.eq)(current, &value));
+
+      if changed {
+        *current = Some(value);
+      }
+
+      changed
+    };
+
+    let weak: Weak<SignalInner<T>> = Rc::downgrade(self);
+    let mut subscriptions = computation.sources.borrow_mut();
+
+    for source in sources {
+      if subscriptions.iter().any(|(x, _)| Rc::ptr_eq(x, &source)) {
+        continue;
+      }
+
+      let id = source.subscribe(Rc::new({
+        let weak = weak.clone();
+
+        move || {
+          if let Some(inner) = weak.upgrade() {
+            inner.invalidate();
+          }
+        }
+      }));
+
+      subscriptions.push((source, id));
+    }
+
+    changed
+  }
+}
+
+impl<T> Source for SignalInner<T> {
+  fn subscribe(&self, listener: Listener) -> u64 {
+    let id = self.next_id.get();
+
+    self.next_id.set(id + 1);
+    self.listeners.borrow_mut().push((id, listener));
+    id
+  }
+
+  fn unsubscribe(&self, id: u64) {
+    self.listeners.borrow_mut().retain(|(x, _)| *x != id);
+  }
+}
+
+impl<T> Drop for SignalInner<T> {
+  fn drop(&mut self) {
+    if let Some(computation) = &self.computation {
+      for (source, id) in computation.sources.take() {
+        source.unsubscribe(id);
+      }
+    }
+  }
+}
+
+/// A reactive value which rerenders only the components that read it with
+/// [`use_signal_value()`](crate::hooks::use_signal_value()) when it changes,
+/// in contrast to [`State`](crate::hooks::State), which rerenders the
+/// component owning the state along with all of its children.
+///
+/// Signals can be created outside of components, e.g. in a `thread_local!`,
+/// or inside of components with [`use_signal()`](crate::hooks::use_signal()).
+/// Cloning a signal returns a handle to the same signal.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// thread_local! {
+///   static COUNTER: Signal<i32> = Signal::new(0);
+///   static DOUBLED: Signal<i32> =
+///     COUNTER.with(|counter| counter.map(|counter| counter * 2));
+/// }
+///
+/// struct Doubled;
+///
+/// impl Component for Doubled {
+///   fn render(&self) -> VNode {
+///     let doubled = DOUBLED.with(|doubled| doubled.clone());
+///     let value = *use_signal_value(&doubled);
+///
+///     h!(p).build(("Doubled: ", value))
+///   }
+/// }
+///
+/// struct App;
+///
+/// impl Component for App {
+///   fn render(&self) -> VNode {
+///     // Clicking the button won't rerender `App`, only `Doubled`
+///     h!(div).build((
+///       h!(button)
+///         .on_click(&Callback::new(|_| {
+///           COUNTER.with(|counter| counter.set(|counter| counter + 1))
+///         }))
+///         .build("Increment"),
+///       Doubled.build(),
+///     ))
+///   }
+/// }
+/// ```
+pub struct Signal<T>(Rc<SignalInner<T>>);
+
+impl<T: 'static> Signal<T> {
+  /// Creates a new signal with the given initial value.
+  pub fn new(value: T) -> Self {
+    Self(Rc::new(SignalInner {
+      value: RefCell::new(Some(value)),
+      version: Cell::new(0),
+      next_id: Cell::new(0),
+      listeners: RefCell::new(Vec::new()),
+      computation: None,
+    }))
+  }
+
+  /// Creates a computed signal whose value is derived from other signals by
+  /// the given function.
+  ///
+  /// All signals read by the function are tracked, so the value will be
+  /// computed again when one of them changes. Subscribed components are only
+  /// rerendered if the computed value differs from the previous one. Computed
+  /// signals without subscribers are computed once they're read. Computed
+  /// signals cannot be set.
+  pub fn computed(compute: impl Fn() -> T + 'static) -> Self
+  where
+    T: PartialEq,
+  {
+    Self(Rc::new(SignalInner {
+      value: RefCell::new(None),
+      version: Cell::new(0),
+      next_id: Cell::new(0),
+      listeners: RefCell::new(Vec::new()),
+      computation: Some(Computation {
+        compute: Box::new(compute),
+        eq: T::eq,
+        dirty: Cell::new(true),
+        sources: RefCell::new(Vec::new()),
+      }),
+    }))
+  }
+
+  /// Creates a computed signal whose value is derived from this signal by the
+  /// given function, see [`Signal::computed()`].
+  pub fn map<U: PartialEq + 'static>(
+    &self,
+    f: impl Fn(&T) -> U + 'static,
+  ) -> Signal<U> {
+    let signal = self.clone();
+
+    Signal::computed(move || f(&signal.get()))
+  }
+
+  /// Returns a reference to the value of the signal.
+  ///
+  /// This won't rerender the component when the signal changes, use
+  /// [`use_signal_value()`](crate::hooks::use_signal_value()) in components.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the value is currently mutably borrowed.
+  pub fn get(&self) -> Ref<'_, T> {
+    TRACKING.with(|tracking| {
+      if let Some(sources) = tracking.borrow_mut().last_mut() {
+        sources.push(self.0.clone());
+      }
+    });
+
+    if let Some(computation) = &self.0.computation {
+      if computation.dirty.get() && self.0.recompute(computation) {
+        self.0.version.set(self.0.version.get().wrapping_add(1));
+      }
+    }
+
+    Ref::map(self.0.value.borrow(), |value| {
+      value.as_ref().expect_throw("no signal value available")
+    })
+  }
+
+  /// Sets the value of the signal to the return value of the given mutator
+  /// closure and rerenders all subscribed components.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the signal is computed or its value is currently borrowed.
+  pub fn set(&self, mutator: impl FnOnce(T) -> T) {
+    assert!(
+      self.0.computation.is_none(),
+      "computed signals cannot be set"
+    );
+
+    {
+      let mut value = self.0.value.borrow_mut();
+      let new_value = value.take().map(mutator);
+
+      *value = new_value;
+    }
+
+    self.0.notify();
+  }
+
+  pub(crate) fn version(&self) -> u64 {
+    self.0.version.get()
+  }
+
+  pub(crate) fn subscribe(&self, listener: Listener) -> u64 {
+    self.0.subscribe(listener)
+  }
+
+  pub(crate) fn unsubscribe(&self, id: u64) {
+    self.0.unsubscribe(id)
+  }
+}
+
+impl<T: Default + 'static> Default for Signal<T> {
+  fn default() -> Self {
+    Self::new(T::default())
+  }
+}
+
+impl<T> Clone for Signal<T> {
+  fn clone(&self) -> Self {
+    Self(self.0.clone())
+  }
+}
+
+impl<T> PartialEq for Signal<T> {
+  fn eq(&self, other: &Self) -> bool {
+    Rc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl<T> Eq for Signal<T> {}
+
+impl<T: Debug> Debug for Signal<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut tuple = f.debug_tuple("Signal");
+
+    match self.0.value.try_borrow().as_deref() {
+      Ok(Some(value)) => tuple.field(value),
+      Ok(None) => tuple.field(&format_args!("<not computed>")),
+      Err(_) => tuple.field(&format_args!("<borrowed>")),
+    };
+
+    tuple.finish()
+  }
+}
@@ -0,0 +1,729 @@
+use super::{
+  js_number_to_string,
+  node::{ElementNode, NodeKind, OwnedElementType},
+  runtime::{self, Instance, TreeContext},
+  Node, PropValue,
+};
+use crate::props::Props;
+use std::borrow::Cow;
+
+/// Escapes text for HTML content and attribute values.
+fn escape(value: &str) -> Cow<'_, str> {
+  if !value.contains(['&', '<', '>', '"', '\'']) {
+    return Cow::Borrowed(value);
+  }
+
+  let mut result = String::with_capacity(value.len() + 16);
+
+  for c in value.chars() {
+    match c {
+      '&' => result.push_str("&amp;"),
+      '<' => result.push_str("&lt;"),
+      '>' => result.push_str("&gt;"),
+      '"' => result.push_str("&quot;"),
+      '\'' => result.push_str("&#x27;"),
+      c => result.push(c),
+    }
+  }
+
+  Cow::Owned(result)
+}
+
+fn is_valid_tag_name(name: &str) -> bool {
+  let mut chars = name.chars();
+
+  chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+    && chars
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '_' | '.' | '-'))
+}
+
+fn is_valid_attribute_name(name: &str) -> bool {
+  let is_start_char = |c: char| {
+    c.is_ascii_alphabetic() || matches!(c, ':' | '_') || !c.is_ascii()
+  };
+  let mut chars = name.chars();
+
+  chars.next().is_some_and(is_start_char)
+    && chars
+      .all(|c| is_start_char(c) || c.is_ascii_digit() || matches!(c, '-' | '.'))
+}
+
+fn is_void_element(tag: &str) -> bool {
+  matches!(
+    tag,
+    "area"
+      | "base"
+      | "br"
+      | "col"
+      | "embed"
+      | "hr"
+      | "img"
+      | "input"
+      | "keygen"
+      | "link"
+      | "meta"
+      | "param"
+      | "source"
+      | "track"
+      | "wbr"
+  )
+}
+
+fn is_custom_element(tag: &str) -> bool {
+  tag.contains('-')
+    && !matches!(
+      tag,
+      "annotation-xml"
+        | "color-profile"
+        | "font-face"
+        | "font-face-src"
+        | "font-face-uri"
+        | "font-face-format"
+        | "font-face-name"
+        | "missing-glyph"
+    )
+}
+
+/// Converts the value to a number the same way JS does.
+fn to_number(value: &PropValue) -> f64 {
+  match value {
+    PropValue::Number(x) => *x,
+    PropValue::Bool(x) => *x as u8 as f64,
+    PropValue::String(x) if x.trim().is_empty() => 0.0,
+    PropValue::String(x) => x.trim().parse().unwrap_or(f64::NAN),
+    _ => f64::NAN,
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum AttributeKind {
+  String,
+  BooleanishString,
+  Boolean,
+  OverloadedBoolean,
+  Numeric,
+  PositiveNumeric,
+}
+
+/// Returns the attribute name and kind of the props that React knows about.
+fn attribute_info(name: &str) -> Option<(Cow<'_, str>, AttributeKind)> {
+  use AttributeKind::*;
+
+  const SVG_ATTRIBUTES: &[&str] = &[
+    "accent-height",
+    "alignment-baseline",
+    "arabic-form",
+    "baseline-shift",
+    "cap-height",
+    "clip-path",
+    "clip-rule",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "color-profile",
+    "color-rendering",
+    "dominant-baseline",
+    "enable-background",
+    "fill-opacity",
+    "fill-rule",
+    "flood-color",
+    "flood-opacity",
+    "font-family",
+    "font-size",
+    "font-size-adjust",
+    "font-stretch",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "glyph-name",
+    "glyph-orientation-horizontal",
+    "glyph-orientation-vertical",
+    "horiz-adv-x",
+    "horiz-origin-x",
+    "image-rendering",
+    "letter-spacing",
+    "lighting-color",
+    "marker-end",
+    "marker-mid",
+    "marker-start",
+    "overline-position",
+    "overline-thickness",
+    "paint-order",
+    "panose-1",
+    "pointer-events",
+    "rendering-intent",
+    "shape-rendering",
+    "stop-color",
+    "stop-opacity",
+    "strikethrough-position",
+    "strikethrough-thickness",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "text-anchor",
+    "text-decoration",
+    "text-rendering",
+    "underline-position",
+    "underline-thickness",
+    "unicode-bidi",
+    "unicode-range",
+    "units-per-em",
+    "v-alphabetic",
+    "v-hanging",
+    "v-ideographic",
+    "v-mathematical",
+    "vector-effect",
+    "vert-adv-y",
+    "vert-origin-x",
+    "vert-origin-y",
+    "word-spacing",
+    "writing-mode",
+    "xmlns:xlink",
+    "x-height",
+  ];
+
+  let lowercase = || Cow::Owned(name.to_ascii_lowercase());
+
+  Some(match name {
+    "acceptCharset" => ("accept-charset".into(), String),
+    "className" => ("class".into(), String),
+    "htmlFor" => ("for".into(), String),
+    "httpEquiv" => ("http-equiv".into(), String),
+    "tabIndex" | "crossOrigin" => (lowercase(), String),
+    "contentEditable" | "draggable" | "spellCheck" | "value" => {
+      (lowercase(), BooleanishString)
+    }
+    "autoReverse"
+    | "externalResourcesRequired"
+    | "focusable"
+    | "preserveAlpha" => (name.into(), BooleanishString),
+    "allowFullScreen"
+    | "async"
+    | "autoFocus"
+    | "autoPlay"
+    | "controls"
+    | "default"
+    | "defer"
+    | "disabled"
+    | "disablePictureInPicture"
+    | "disableRemotePlayback"
+    | "formNoValidate"
+    | "hidden"
+    | "loop"
+    | "noModule"
+    | "noValidate"
+    | "open"
+    | "playsInline"
+    | "readOnly"
+    | "required"
+    | "reversed"
+    | "scoped"
+    | "seamless"
+    | "itemScope" => (lowercase(), Boolean),
+    "checked" | "multiple" | "muted" | "selected" => (name.into(), Boolean),
+    "capture" | "download" => (name.into(), OverloadedBoolean),
+    "cols" | "rows" | "size" | "span" => (name.into(), PositiveNumeric),
+    "rowSpan" | "start" => (lowercase(), Numeric),
+    "xlinkActuate" | "xlinkArcrole" | "xlinkHref" | "xlinkRole"
+    | "xlinkShow" | "xlinkTitle" | "xlinkType" => (
+      format!("xlink:{}", name[5..].to_ascii_lowercase()).into(),
+      String,
+    ),
+    "xmlBase" | "xmlLang" | "xmlSpace" => (
+      format!("xml:{}", name[3..].to_ascii_lowercase()).into(),
+      String,
+    ),
+    _ => {
+      // SVG attributes with dashes or colons are written in camel case
+      let attribute = SVG_ATTRIBUTES.iter().find(|attribute| {
+        let mut chars = attribute.chars().peekable();
+        let mut camel_case = std::string::String::new();
+
+        while let Some(c) = chars.next() {
+          match chars.peek() {
+            Some(next)
+              if matches!(c, '-' | ':') && next.is_ascii_lowercase() =>
+            {
+              camel_case.push(next.to_ascii_uppercase());
+              chars.next();
+            }
+            _ => camel_case.push(c),
+          }
+        }
+
+        camel_case == name
+      })?;
+
+      ((*attribute).into(), String)
+    }
+  })
+}
+
+fn is_unitless_style(name: &str) -> bool {
+  // Strip vendor prefixes, e.g. `WebkitLineClamp`
+  let name = ["Webkit", "ms", "Moz", "O"]
+    .iter()
+    .find_map(|prefix| {
+      name
+        .strip_prefix(prefix)
+        .filter(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+    })
+    .map(|rest| {
+      let mut chars = rest.chars();
+      chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+    })
+    .map(Cow::Owned)
+    .unwrap_or(Cow::Borrowed(name));
+
+  matches!(
+    &*name,
+    "animationIterationCount"
+      | "aspectRatio"
+      | "borderImageOutset"
+      | "borderImageSlice"
+      | "borderImageWidth"
+      | "boxFlex"
+      | "boxFlexGroup"
+      | "boxOrdinalGroup"
+      | "columnCount"
+      | "columns"
+      | "flex"
+      | "flexGrow"
+      | "flexPositive"
+      | "flexShrink"
+      | "flexNegative"
+      | "flexOrder"
+      | "gridArea"
+      | "gridRow"
+      | "gridRowEnd"
+      | "gridRowSpan"
+      | "gridRowStart"
+      | "gridColumn"
+      | "gridColumnEnd"
+      | "gridColumnSpan"
+      | "gridColumnStart"
+      | "fontWeight"
+      | "lineClamp"
+      | "lineHeight"
+      | "opacity"
+      | "order"
+      | "orphans"
+      | "tabSize"
+      | "widows"
+      | "zIndex"
+      | "zoom"
+      | "fillOpacity"
+      | "floodOpacity"
+      | "stopOpacity"
+      | "strokeDasharray"
+      | "strokeDashoffset"
+      | "strokeMiterlimit"
+      | "strokeOpacity"
+      | "strokeWidth"
+  )
+}
+
+fn hyphenate_style_name(name: &str) -> String {
+  let mut result = String::with_capacity(name.len() + 4);
+
+  for c in name.chars() {
+    if c.is_ascii_uppercase() {
+      result.push('-');
+      result.push(c.to_ascii_lowercase());
+    } else {
+      result.push(c);
+    }
+  }
+
+  if result.starts_with("ms-") {
+    result.insert(0, '-');
+  }
+
+  result
+}
+
+/// Serializes a style object into CSS declarations.
+fn style_to_css(style: &Props) -> String {
+  let mut result = String::new();
+
+  for (name, value) in style.iter() {
+    let value = match value {
+      PropValue::Number(x) if !name.starts_with("--") => {
+        if *x != 0.0 && !is_unitless_style(name) {
+          js_number_to_string(*x) + "px"
+        } else {
+          js_number_to_string(*x)
+        }
+      }
+      PropValue::Number(_) | PropValue::String(_) => {
+        value.to_js_string().unwrap_or_default().trim().to_string()
+      }
+      _ => continue,
+    };
+
+    if value.is_empty() {
+      continue;
+    }
+
+    if !result.is_empty() {
+      result.push(';');
+    }
+
+    if name.starts_with("--") {
+      result.push_str(&escape(name));
+    } else {
+      result.push_str(&escape(&hyphenate_style_name(name)));
+    }
+
+    result.push(':');
+    result.push_str(&escape(&value));
+  }
+
+  result
+}
+
+/// Returns the concatenated text of the given children, e.g. to determine
+/// the value of an `<option>` element.
+fn text_content(node: &Node) -> String {
+  match &node.0 {
+    NodeKind::Text(text) => text.to_string(),
+    NodeKind::Array(nodes) => nodes.iter().map(text_content).collect(),
+    _ => String::new(),
+  }
+}
+
+/// Writes a server-side element tree as HTML the same way `react-dom/server`
+/// does, so it can be hydrated by React.
+#[derive(Default)]
+pub(crate) struct HtmlRenderer {
+  html: String,
+  last_pushed_text: bool,
+  selected_value: Option<PropValue>,
+}
+
+impl HtmlRenderer {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn finish(self) -> String {
+    self.html
+  }
+
+  pub fn render(&mut self, node: &Node, tree_context: &TreeContext) {
+    match &node.0 {
+      NodeKind::Empty => {}
+      NodeKind::Text(text) => self.push_text(text),
+      NodeKind::Array(nodes) => {
+        for (i, node) in nodes.iter().enumerate() {
+          self.render(node, &tree_context.fork(nodes.len(), i));
+        }
+      }
+      NodeKind::Element(element) => match &element.typ {
+        OwnedElementType::Html(tag) => {
+          self.render_html_element(tag, element, tree_context)
+        }
+        OwnedElementType::Suspense => {
+          self.push_markup("<!--$-->");
+          self.render(&element.children, tree_context);
+          self.push_markup("<!--/$-->");
+        }
+        OwnedElementType::Transparent => {
+          self.render(&element.children, tree_context)
+        }
+        // JS components cannot be rendered on the server
+        OwnedElementType::Unsupported => {}
+      },
+      NodeKind::Component(component) => {
+        // Effects don't run on the server
+        let rendered = runtime::render_component(
+          &*component.component,
+          &Instance::new(None),
+          tree_context,
+        );
+        let children = Node::from(rendered.children);
+
+        if rendered.did_use_id {
+          // React forks the tree context to keep ids of children unique
+          self.render(&children, &tree_context.fork(1, 0));
+        } else {
+          self.render(&children, tree_context);
+        }
+      }
+      NodeKind::Provider(provider) => match &provider.value {
+        Some(value) => {
+          runtime::with_context(provider.context, value.clone(), || {
+            self.render(&provider.children, tree_context)
+          })
+        }
+        None => self.render(&provider.children, tree_context),
+      },
+    }
+  }
+
+  fn push_text(&mut self, text: &str) {
+    if text.is_empty() {
+      return;
+    }
+
+    if self.last_pushed_text {
+      // Separate adjacent text nodes, so they can be hydrated individually
+      self.html.push_str("<!-- -->");
+    }
+
+    self.html.push_str(&escape(text));
+    self.last_pushed_text = true;
+  }
+
+  fn push_markup(&mut self, markup: &str) {
+    self.html.push_str(markup);
+    self.last_pushed_text = false;
+  }
+
+  fn push_attribute(&mut self, name: &str, value: &str) {
+    self.html.push(' ');
+    self.html.push_str(name);
+    self.html.push_str("=\"");
+    self.html.push_str(&escape(value));
+    self.html.push('"');
+  }
+
+  fn push_prop(&mut self, name: &str, value: &PropValue) {
+    if name.len() > 2
+      && name.get(..2).is_some_and(|x| x.eq_ignore_ascii_case("on"))
+    {
+      // Event handlers are only attached on the client
+      return;
+    }
+
+    if matches!(
+      value,
+      PropValue::Object(_) | PropValue::Node(_) | PropValue::Function(_)
+    ) {
+      return;
+    }
+
+    let Some((attribute, kind)) = attribute_info(name) else {
+      if !is_valid_attribute_name(name) {
+        return;
+      }
+
+      if let PropValue::Bool(_) = value {
+        let prefix = name.get(..5).unwrap_or_default().to_ascii_lowercase();
+
+        if prefix != "data-" && prefix != "aria-" {
+          return;
+        }
+      }
+
+      if let Some(value) = value.to_js_string() {
+        self.push_attribute(name, &value);
+      }
+
+      return;
+    };
+
+    if let PropValue::Bool(_) = value {
+      if matches!(
+        kind,
+        AttributeKind::String
+          | AttributeKind::Numeric
+          | AttributeKind::PositiveNumeric
+      ) {
+        return;
+      }
+    }
+
+    match kind {
+      AttributeKind::Boolean => {
+        if value.is_truthy() {
+          self.push_attribute(&attribute, "");
+        }
+        return;
+      }
+      AttributeKind::OverloadedBoolean => match value {
+        PropValue::Bool(true) => {
+          self.push_attribute(&attribute, "");
+          return;
+        }
+        PropValue::Bool(false) => return,
+        _ => {}
+      },
+      AttributeKind::Numeric if to_number(value).is_nan() => return,
+      AttributeKind::PositiveNumeric => {
+        let number = to_number(value);
+
+        if number.is_nan() || number < 1.0 {
+          return;
+        }
+      }
+      _ => {}
+    }
+
+    if let Some(value) = value.to_js_string() {
+      self.push_attribute(&attribute, &value);
+    }
+  }
+
+  fn render_html_element(
+    &mut self,
+    tag: &str,
+    element: &ElementNode,
+    tree_context: &TreeContext,
+  ) {
+    assert!(is_valid_tag_name(tag), "invalid tag: {tag}");
+
+    let custom_element = is_custom_element(tag);
+    let mut inner_html = None;
+    let mut value = None;
+    let mut default_value = None;
+    let mut checked = None;
+    let mut default_checked = None;
+    let mut selected = None;
+
+    self.html.push('<');
+    self.html.push_str(tag);
+
+    for (name, prop) in element.props.iter() {
+      if matches!(prop, PropValue::Undefined | PropValue::Null) {
+        continue;
+      }
+
+      match name {
+        "children"
+        | "key"
+        | "ref"
+        | "innerHTML"
+        | "suppressContentEditableWarning"
+        | "suppressHydrationWarning" => {}
+        "dangerouslySetInnerHTML" => {
+          if let PropValue::Object(props) = prop {
+            inner_html = props.get("__html").and_then(|x| x.to_js_string());
+          }
+        }
+        "style" => {
+          if let PropValue::Object(style) = prop {
+            let css = style_to_css(style);
+
+            if !css.is_empty() {
+              self.push_attribute("style", &css);
+            }
+          }
+        }
+        "value" if matches!(tag, "input" | "textarea" | "select") => {
+          value = Some(prop)
+        }
+        "value" if tag == "option" => {
+          value = Some(prop);
+          self.push_prop(name, prop);
+        }
+        "defaultValue" => default_value = Some(prop),
+        "checked" if tag == "input" => checked = Some(prop),
+        "defaultChecked" => default_checked = Some(prop),
+        "selected" if tag == "option" => selected = Some(prop),
+        _ if custom_element => {
+          if is_valid_attribute_name(name) {
+            let name = if name == "className" { "class" } else { name };
+
+            if let Some(value) = prop.to_js_string() {
+              self.push_attribute(name, &value);
+            }
+          }
+        }
+        _ => self.push_prop(name, prop),
+      }
+    }
+
+    match tag {
+      "input" => {
+        if checked.or(default_checked).is_some_and(|x| x.is_truthy()) {
+          self.push_attribute("checked", "");
+        }
+
+        if let Some(value) = value.or(default_value) {
+          self.push_prop("value", value);
+        }
+      }
+      "option" => {
+        let is_selected = match &self.selected_value {
+          Some(selected_value) => {
+            let value = match value {
+              Some(value) => value.to_js_string().unwrap_or_default(),
+              None => text_content(&element.children),
+            };
+
+            selected_value.to_js_string().as_ref() == Some(&value)
+          }
+          None => selected.is_some_and(|x| x.is_truthy()),
+        };
+
+        if is_selected {
+          self.push_attribute("selected", "");
+        }
+      }
+      _ => {}
+    }
+
+    if is_void_element(tag) {
+      self.push_markup("/>");
+      return;
+    }
+
+    self.push_markup(">");
+
+    match tag {
+      "textarea" => {
+        let value = value
+          .or(default_value)
+          .and_then(|x| x.to_js_string())
+          .unwrap_or_else(|| text_content(&element.children));
+
+        if value.starts_with('\n') {
+          // Browsers strip a leading newline
+          self.html.push('\n');
+        }
+
+        self.html.push_str(&escape(&value));
+      }
+      _ => {
+        if let Some(inner_html) = inner_html {
+          if matches!(tag, "pre" | "listing") && inner_html.starts_with('\n') {
+            self.html.push('\n');
+          }
+
+          self.html.push_str(&inner_html);
+        } else {
+          if matches!(tag, "pre" | "listing")
+            && matches!(&element.children.0, NodeKind::Text(text) if text.starts_with('\n'))
+          {
+            self.html.push('\n');
+          }
+
+          let selected_value = match tag {
+            "select" => Some(value.or(default_value).cloned()),
+            _ => None,
+          };
+          let prev_selected_value = selected_value.map(|selected_value| {
+            std::mem::replace(&mut self.selected_value, selected_value)
+          });
+
+          self.render(&element.children, tree_context);
+
+          if let Some(prev_selected_value) = prev_selected_value {
+            self.selected_value = prev_selected_value;
+          }
+        }
+      }
+    }
+
+    self.push_markup("</");
+    self.html.push_str(tag);
+    self.html.push('>');
+  }
+}
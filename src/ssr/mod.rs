@@ -0,0 +1,79 @@
+//! This module provides server-side rendering of components to HTML strings in
+//! pure Rust, e.g. to prerender pages at build time without a JS runtime.
+//!
+//! Requires the `native-runtime` feature and a target without JS. With this
+//! feature enabled, [`VNode`], [`Props`] and
+//! [`Component::build()`](crate::Component::build()) build an in-memory element
+//! tree instead of calling into React, so components can be rendered on native
+//! targets. Use [`hydrate_root()`] on the client to make the prerendered HTML
+//! interactive.
+//!
+//! The feature has no effect on wasm targets with JS, so the client keeps using
+//! React even if Cargo unifies the feature into its build. On native targets,
+//! items which need JS values, e.g. [`create_element()`](crate::create_element)
+//! or conversions into [`JsValue`](wasm_bindgen::JsValue), are still available
+//! but panic when used.
+//!
+//! On the server, effects won't run, state updates won't rerender, refs stay
+//! empty, and event handlers are omitted. Components imported from JS cannot be
+//! rendered and will be left out.
+//!
+//! [`Props`]: crate::props::Props
+//! [`hydrate_root()`]: https://react.dev/reference/react-dom/client/hydrateRoot
+
+mod html;
+mod node;
+mod runtime;
+
+pub(crate) use node::*;
+pub(crate) use runtime::*;
+
+#[doc(hidden)]
+pub use node::ElementType;
+pub use node::{Node, PropValue};
+
+use crate::VNode;
+use html::HtmlRenderer;
+
+/// Panics since the given operation needs JS values, which only exist with the
+/// React runtime.
+#[track_caller]
+pub(crate) fn unsupported(operation: &str) -> ! {
+  panic!("{operation} is not supported by the native runtime")
+}
+
+/// Renders the given node to an HTML string which can be hydrated by React.
+///
+/// Equivalent to `renderToString` of `react-dom/server`.
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*};
+/// struct Counter {
+///   initial: i32,
+/// }
+///
+/// impl Component for Counter {
+///   fn render(&self) -> VNode {
+///     let counter = use_state(|| self.initial);
+///     let value = *counter.value();
+///
+///     h!(div[#"counter"]).build((
+///       h!(p).build(("Counter: ", value)),
+///       h!(button).on_click(&Callback::noop()).build("Increment"),
+///     ))
+///   }
+/// }
+///
+/// assert_eq!(
+///   ssr::render_to_string(Counter { initial: 5 }.build()),
+///   r#"<div id="counter"><p>Counter: <!-- -->5</p><button>Increment</button></div>"#
+/// );
+/// ```
+pub fn render_to_string(children: impl Into<VNode>) -> String {
+  let mut renderer = HtmlRenderer::new();
+
+  renderer.render(&children.into().into(), &TreeContext::root());
+  renderer.finish()
+}
@@ -0,0 +1,336 @@
+use crate::{component::ObjectSafeComponent, props::Props, VNode};
+use js_sys::JsString;
+use std::{any::Any, fmt::Debug, rc::Rc};
+
+/// Represents a value of a prop in the server-side element tree. Replaces
+/// [`JsValue`](wasm_bindgen::JsValue) as [`PropValue`](crate::props::PropValue)
+/// when the `native-runtime` feature is enabled.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone)]
+pub enum PropValue {
+  /// Corresponds to `undefined`.
+  #[default]
+  Undefined,
+  /// Corresponds to `null`.
+  Null,
+  /// A boolean value.
+  Bool(bool),
+  /// A number value.
+  Number(f64),
+  /// A string value.
+  String(Rc<str>),
+  /// A nested object, e.g. a [`Style`](crate::props::Style).
+  Object(Props),
+  /// A node of the element tree.
+  Node(VNode),
+  /// A [`Callback`](crate::Callback) which will not be rendered.
+  Function(Rc<dyn Any>),
+}
+
+impl PropValue {
+  /// Returns the string representation of the value as JS would convert it to
+  /// a string, if it's a string, number, or boolean.
+  pub(crate) fn to_js_string(&self) -> Option<String> {
+    match self {
+      PropValue::Bool(x) => Some(x.to_string()),
+      PropValue::Number(x) => Some(js_number_to_string(*x)),
+      PropValue::String(x) => Some(x.to_string()),
+      _ => None,
+    }
+  }
+
+  /// Returns whether the value is truthy in JS.
+  pub(crate) fn is_truthy(&self) -> bool {
+    match self {
+      PropValue::Undefined | PropValue::Null => false,
+      PropValue::Bool(x) => *x,
+      PropValue::Number(x) => *x != 0.0 && !x.is_nan(),
+      PropValue::String(x) => !x.is_empty(),
+      _ => true,
+    }
+  }
+}
+
+impl PartialEq for PropValue {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (PropValue::Undefined, PropValue::Undefined)
+      | (PropValue::Null, PropValue::Null) => true,
+      (PropValue::Bool(x), PropValue::Bool(y)) => x == y,
+      (PropValue::Number(x), PropValue::Number(y)) => x == y,
+      (PropValue::String(x), PropValue::String(y)) => x == y,
+      (PropValue::Object(x), PropValue::Object(y)) => x == y,
+      (PropValue::Function(x), PropValue::Function(y)) => Rc::ptr_eq(x, y),
+      _ => false,
+    }
+  }
+}
+
+macro_rules! impl_from_for_prop_value {
+  { strings: $( $S:ty ),*; numbers: $( $N:ty ),* $(,)? } => {
+    $(
+      impl From<$S> for PropValue {
+        fn from(value: $S) -> Self {
+          PropValue::String(Rc::from(String::from(value)))
+        }
+      }
+    )*
+
+    $(
+      impl From<$N> for PropValue {
+        fn from(value: $N) -> Self {
+          PropValue::Number(value as f64)
+        }
+      }
+    )*
+  };
+}
+
+impl_from_for_prop_value! {
+  strings: &str, String, &String, char;
+  numbers:
+    f32, f64,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+}
+
+impl From<bool> for PropValue {
+  fn from(value: bool) -> Self {
+    PropValue::Bool(value)
+  }
+}
+
+impl From<JsString> for PropValue {
+  fn from(value: JsString) -> Self {
+    PropValue::String(Rc::from(String::from(value)))
+  }
+}
+
+impl From<Rc<str>> for PropValue {
+  fn from(value: Rc<str>) -> Self {
+    PropValue::String(value)
+  }
+}
+
+impl<T: Into<PropValue>> From<Option<T>> for PropValue {
+  fn from(value: Option<T>) -> Self {
+    value.map(|x| x.into()).unwrap_or_default()
+  }
+}
+
+/// Converts a number to a string the same way JS does for common values.
+pub(crate) fn js_number_to_string(value: f64) -> String {
+  if value.is_nan() {
+    "NaN".into()
+  } else if value.is_infinite() {
+    if value > 0.0 { "Infinity" } else { "-Infinity" }.into()
+  } else if value == 0.0 {
+    // Also covers negative zero
+    "0".into()
+  } else if (1e-6..1e21).contains(&value.abs()) {
+    value.to_string()
+  } else {
+    // JS switches to exponential notation for very large and small numbers
+    let result = format!("{value:e}");
+
+    match result.split_once('e') {
+      Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+        format!("{mantissa}e+{exponent}")
+      }
+      _ => result,
+    }
+  }
+}
+
+/// The type of element an [`H`](crate::props::H) builder creates on the
+/// server.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum ElementType<'a> {
+  /// An HTML element with the given tag name.
+  Html(&'a str),
+  /// A `React.Suspense` boundary.
+  Suspense,
+  /// A component that renders its children, e.g. an error boundary.
+  Transparent,
+  /// A JS component which cannot be rendered on the server.
+  Unsupported,
+}
+
+#[derive(PartialEq)]
+pub(crate) enum OwnedElementType {
+  Html(Rc<str>),
+  Suspense,
+  Transparent,
+  Unsupported,
+}
+
+impl From<ElementType<'_>> for OwnedElementType {
+  fn from(value: ElementType<'_>) -> Self {
+    match value {
+      ElementType::Html(tag) => OwnedElementType::Html(Rc::from(tag)),
+      ElementType::Suspense => OwnedElementType::Suspense,
+      ElementType::Transparent => OwnedElementType::Transparent,
+      ElementType::Unsupported => OwnedElementType::Unsupported,
+    }
+  }
+}
+
+pub(crate) struct ElementNode {
+  pub typ: OwnedElementType,
+  pub props: Props,
+  pub children: Node,
+}
+
+/// Compares the props of two memoized components of the same type.
+pub(crate) type MemoEq = fn(&dyn Any, &dyn Any) -> bool;
+
+pub(crate) struct ComponentNode {
+  pub name: &'static str,
+  pub key: Option<PropValue>,
+  pub component: Box<dyn ObjectSafeComponent>,
+  /// Compares the props of memoized components.
+  pub memo: Option<MemoEq>,
+}
+
+impl ComponentNode {
+  /// Returns whether both components are memoized and have equal props.
+  #[cfg_attr(not(native_testing), allow(dead_code))]
+  pub fn memo_eq(&self, other: &ComponentNode) -> bool {
+    self.memo.is_some_and(|eq| {
+      other.memo.is_some()
+        && eq(self.component.as_any(), other.component.as_any())
+    })
+  }
+}
+
+pub(crate) struct ProviderNode {
+  pub context: usize,
+  pub value: Option<Rc<dyn Any>>,
+  pub children: Node,
+}
+
+#[derive(Clone)]
+pub(crate) enum NodeKind {
+  Empty,
+  Text(Rc<str>),
+  Array(Rc<[Node]>),
+  Element(Rc<ElementNode>),
+  Component(Rc<ComponentNode>),
+  Provider(Rc<ProviderNode>),
+}
+
+/// A node of the server-side element tree which replaces React elements when
+/// the `native-runtime` feature is enabled.
+#[derive(Clone)]
+pub struct Node(pub(crate) NodeKind);
+
+impl Node {
+  pub(crate) fn empty() -> Self {
+    Node(NodeKind::Empty)
+  }
+
+  pub(crate) fn text(value: impl Into<Rc<str>>) -> Self {
+    Node(NodeKind::Text(value.into()))
+  }
+
+  pub(crate) fn is_empty(&self) -> bool {
+    matches!(self.0, NodeKind::Empty)
+  }
+
+  /// Converts the children of an element, spreading lists the same way
+  /// `React.createElement` does.
+  fn from_children(children: VNode) -> Self {
+    match children {
+      VNode::Native(node) => node,
+      VNode::NativeList(mut nodes) => match nodes.len() {
+        0 => Node::empty(),
+        1 => nodes.remove(0),
+        _ => Node(NodeKind::Array(nodes.into())),
+      },
+      _ => super::unsupported("rendering JS nodes"),
+    }
+  }
+
+  pub(crate) fn element(
+    typ: ElementType,
+    props: Props,
+    children: VNode,
+  ) -> Self {
+    Node(NodeKind::Element(Rc::new(ElementNode {
+      typ: typ.into(),
+      props,
+      children: Node::from_children(children),
+    })))
+  }
+
+  pub(crate) fn component(
+    name: &'static str,
+    key: Option<PropValue>,
+    component: Box<dyn ObjectSafeComponent>,
+    memo: Option<MemoEq>,
+  ) -> Self {
+    Node(NodeKind::Component(Rc::new(ComponentNode {
+      name,
+      key,
+      component,
+      memo,
+    })))
+  }
+
+  pub(crate) fn provider(
+    context: usize,
+    value: Option<Rc<dyn Any>>,
+    children: VNode,
+  ) -> Self {
+    Node(NodeKind::Provider(Rc::new(ProviderNode {
+      context,
+      value,
+      children: Node::from_children(children),
+    })))
+  }
+}
+
+impl Debug for Node {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match &self.0 {
+      NodeKind::Empty => f.write_str("Empty"),
+      NodeKind::Text(text) => f.debug_tuple("Text").field(text).finish(),
+      NodeKind::Array(nodes) => f.debug_list().entries(nodes.iter()).finish(),
+      NodeKind::Element(element) => f
+        .debug_struct("Element")
+        .field(
+          "type",
+          &match &element.typ {
+            OwnedElementType::Html(tag) => &**tag,
+            OwnedElementType::Suspense => "Suspense",
+            OwnedElementType::Transparent => "Transparent",
+            OwnedElementType::Unsupported => "Unsupported",
+          },
+        )
+        .field("props", &element.props)
+        .field("children", &element.children)
+        .finish(),
+      NodeKind::Component(component) => f
+        .debug_struct("Component")
+        .field("name", &component.name)
+        .field("key", &component.key)
+        .finish(),
+      NodeKind::Provider(provider) => f
+        .debug_struct("Provider")
+        .field("children", &provider.children)
+        .finish(),
+    }
+  }
+}
+
+impl From<VNode> for Node {
+  /// Converts the node like a component return value, i.e. lists stay arrays.
+  fn from(value: VNode) -> Self {
+    match value {
+      VNode::Native(node) => node,
+      VNode::NativeList(nodes) => Node(NodeKind::Array(nodes.into())),
+      _ => super::unsupported("rendering JS nodes"),
+    }
+  }
+}
@@ -0,0 +1,385 @@
+use crate::{component::ObjectSafeComponent, VNode};
+use std::{
+  any::Any,
+  cell::{Cell, RefCell},
+  fmt::Debug,
+  rc::{Rc, Weak},
+};
+use wasm_bindgen::UnwrapThrowExt;
+
+/// Identifies the position of a component in the tree, so `use_id()`
+/// generates the same ids as React does when hydrating.
+#[derive(Debug, Clone)]
+pub(crate) struct TreeContext {
+  id: u32,
+  overflow: String,
+}
+
+fn bit_length(value: u32) -> u32 {
+  32 - value.leading_zeros()
+}
+
+fn to_base32(mut value: u32) -> String {
+  const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuv";
+
+  let mut result = vec![DIGITS[(value % 32) as usize]];
+  value /= 32;
+
+  while value > 0 {
+    result.push(DIGITS[(value % 32) as usize]);
+    value /= 32;
+  }
+
+  result.reverse();
+  String::from_utf8(result).unwrap_throw()
+}
+
+impl TreeContext {
+  pub fn root() -> Self {
+    Self {
+      id: 1,
+      overflow: String::new(),
+    }
+  }
+
+  /// Returns the context of the child at the given index, mirroring
+  /// `pushTreeContext` of React.
+  pub fn fork(&self, total_children: usize, index: usize) -> Self {
+    let base_length = bit_length(self.id) - 1;
+    let base_id = self.id & !(1 << base_length);
+    let slot = index as u32 + 1;
+    let length = bit_length(total_children as u32) + base_length;
+
+    if length > 30 {
+      // The id doesn't fit into 32 bits anymore, so we move the lower bits of
+      // the base id into the overflow string
+      let overflow_bits = base_length - base_length % 5;
+      let new_overflow = to_base32(base_id & ((1 << overflow_bits) - 1));
+      let rest_of_base_id = base_id >> overflow_bits;
+      let rest_of_base_length = base_length - overflow_bits;
+      let rest_of_length =
+        bit_length(total_children as u32) + rest_of_base_length;
+      let id = (slot << rest_of_base_length) | rest_of_base_id;
+
+      Self {
+        id: (1 << rest_of_length) | id,
+        overflow: new_overflow + &self.overflow,
+      }
+    } else {
+      Self {
+        id: (1 << length) | (slot << base_length) | base_id,
+        overflow: self.overflow.clone(),
+      }
+    }
+  }
+
+  fn tree_id(&self) -> String {
+    let id = self.id & !(1 << (bit_length(self.id) - 1));
+
+    to_base32(id) + &self.overflow
+  }
+}
+
+type ContextValue = (usize, Option<Rc<dyn Any>>);
+
+/// Holds the hook data of a component across renders.
+#[derive(Default)]
+pub(crate) struct Instance {
+  hooks: RefCell<Vec<Rc<dyn Any>>>,
+  /// The context values read during the last render.
+  contexts: RefCell<Vec<ContextValue>>,
+  dirty: Cell<bool>,
+  unmounted: Cell<bool>,
+  on_update: Option<Rc<dyn Fn()>>,
+}
+
+impl Instance {
+  /// Creates a new instance which calls `on_update` whenever one of its state
+  /// updaters is called.
+  pub fn new(on_update: Option<Rc<dyn Fn()>>) -> Rc<Self> {
+    Rc::new(Self {
+      on_update,
+      ..Default::default()
+    })
+  }
+
+  /// Returns whether the state of the component has been updated or one of
+  /// the context values read during the last render has been changed by its
+  /// provider.
+  #[cfg_attr(not(native_testing), allow(dead_code))]
+  pub fn needs_render(&self) -> bool {
+    self.dirty.get()
+      || self.contexts.borrow().iter().any(|(context, value)| {
+        match (find_context(*context), value) {
+          (Some(x), Some(y)) => !Rc::ptr_eq(&x, y),
+          (x, y) => x.is_some() != y.is_some(),
+        }
+      })
+  }
+
+  /// Runs the clean-up functions of all effects.
+  #[cfg_attr(not(native_testing), allow(dead_code))]
+  pub fn unmount(&self) {
+    self.unmounted.set(true);
+
+    let hooks = self.hooks.borrow().clone();
+
+    for hook in hooks {
+      if let Some(slot) = hook.downcast_ref::<EffectSlot>() {
+        let destructor = slot.0.borrow_mut().take();
+
+        if let Some(destructor) = destructor {
+          destructor();
+        }
+      }
+    }
+  }
+}
+
+/// An effect which has been scheduled during render.
+#[cfg_attr(not(native_testing), allow(dead_code))]
+pub(crate) struct Effect {
+  pub layout: bool,
+  pub run: Box<dyn FnOnce()>,
+}
+
+/// Holds the clean-up function of an effect.
+#[derive(Default)]
+struct EffectSlot(RefCell<Option<Box<dyn FnOnce()>>>);
+
+/// The result of [`render_component()`].
+pub(crate) struct Rendered {
+  pub children: VNode,
+  /// Whether the component has called `use_id()`.
+  pub did_use_id: bool,
+  #[cfg_attr(not(native_testing), allow(dead_code))]
+  pub effects: Vec<Effect>,
+}
+
+struct Frame {
+  instance: Rc<Instance>,
+  hook_index: usize,
+  tree_context: TreeContext,
+  local_id_counter: u32,
+  effects: Vec<Effect>,
+}
+
+thread_local! {
+  static CURRENT_FRAME: RefCell<Option<Frame>> = const { RefCell::new(None) };
+  static CONTEXT_STACK: RefCell<Vec<(usize, Rc<dyn Any>)>> =
+    const { RefCell::new(Vec::new()) };
+}
+
+/// Restores the previous frame even if rendering panics.
+struct FrameGuard(Option<Frame>);
+
+impl Drop for FrameGuard {
+  fn drop(&mut self) {
+    CURRENT_FRAME.with(|frame| *frame.borrow_mut() = self.0.take());
+  }
+}
+
+/// Renders the given component with the hook data of the given instance.
+pub(crate) fn render_component(
+  component: &dyn ObjectSafeComponent,
+  instance: &Rc<Instance>,
+  tree_context: &TreeContext,
+) -> Rendered {
+  instance.dirty.set(false);
+  instance.contexts.borrow_mut().clear();
+
+  let _guard = FrameGuard(CURRENT_FRAME.with(|frame| {
+    frame.borrow_mut().replace(Frame {
+      instance: instance.clone(),
+      hook_index: 0,
+      tree_context: tree_context.clone(),
+      local_id_counter: 0,
+      effects: Vec::new(),
+    })
+  }));
+
+  let children = component.render();
+  let (did_use_id, effects) = with_frame(|frame| {
+    (
+      frame.local_id_counter > 0,
+      std::mem::take(&mut frame.effects),
+    )
+  });
+
+  Rendered {
+    children,
+    did_use_id,
+    effects,
+  }
+}
+
+fn with_frame<R>(f: impl FnOnce(&mut Frame) -> R) -> R {
+  CURRENT_FRAME.with(|frame| {
+    f(frame
+      .borrow_mut()
+      .as_mut()
+      .expect_throw("hooks can only be called inside of a render function"))
+  })
+}
+
+/// Returns the hook data at the current position, initializing it with the
+/// given closure if necessary.
+pub(crate) fn use_hook<T: 'static>(init: impl FnOnce() -> T) -> Rc<T> {
+  let (instance, index) = with_frame(|frame| {
+    frame.hook_index += 1;
+    (frame.instance.clone(), frame.hook_index - 1)
+  });
+  let hook = instance.hooks.borrow().get(index).cloned();
+
+  match hook {
+    Some(hook) => hook
+      .downcast::<T>()
+      .ok()
+      .expect_throw("mismatched hook type"),
+    None => {
+      // Initialize outside of any borrows
+      let hook = Rc::new(init());
+      instance.hooks.borrow_mut().push(hook.clone());
+      hook
+    }
+  }
+}
+
+/// Rerenders the component it has been created in.
+#[derive(Clone)]
+pub(crate) struct Updater(Weak<Instance>);
+
+impl Updater {
+  pub fn schedule(&self) {
+    if let Some(instance) = self.0.upgrade() {
+      if instance.unmounted.get() {
+        return;
+      }
+
+      instance.dirty.set(true);
+
+      if let Some(on_update) = &instance.on_update {
+        on_update();
+      }
+    }
+  }
+}
+
+impl Debug for Updater {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("Updater").finish()
+  }
+}
+
+/// Returns an [`Updater`] for the current component.
+pub(crate) fn use_updater() -> Updater {
+  with_frame(|frame| Updater(Rc::downgrade(&frame.instance)))
+}
+
+/// Returns whether the current component is rendered to a string, in contrast
+/// to the test renderer, which behaves like a client.
+pub(crate) fn is_rendering_to_string() -> bool {
+  with_frame(|frame| frame.instance.on_update.is_none())
+}
+
+/// Schedules the given effect if `changed` is `true`. Its clean-up function
+/// will be called before the next effect runs or when the component unmounts.
+pub(crate) fn use_effect(
+  layout: bool,
+  changed: bool,
+  effect: impl FnOnce() -> Box<dyn FnOnce()> + 'static,
+) {
+  let slot = use_hook(EffectSlot::default);
+
+  if changed {
+    with_frame(|frame| {
+      let instance = frame.instance.clone();
+
+      frame.effects.push(Effect {
+        layout,
+        run: Box::new(move || {
+          if instance.unmounted.get() {
+            return;
+          }
+
+          let destructor = slot.0.borrow_mut().take();
+
+          if let Some(destructor) = destructor {
+            destructor();
+          }
+
+          let destructor = effect();
+          *slot.0.borrow_mut() = Some(destructor);
+        }),
+      });
+    });
+  }
+}
+
+/// Generates an id in the same format as React.
+pub(crate) fn use_id() -> String {
+  with_frame(|frame| {
+    let local_id = frame.local_id_counter;
+    let mut id = format!(":R{}", frame.tree_context.tree_id());
+
+    frame.local_id_counter += 1;
+
+    if local_id > 0 {
+      id.push('H');
+      id.push_str(&to_base32(local_id));
+    }
+
+    id.push(':');
+    id
+  })
+}
+
+/// Pops the context value even if rendering panics.
+struct ContextGuard;
+
+impl Drop for ContextGuard {
+  fn drop(&mut self) {
+    CONTEXT_STACK.with(|stack| stack.borrow_mut().pop());
+  }
+}
+
+/// Provides the given context value while calling `f`.
+pub(crate) fn with_context<R>(
+  context: usize,
+  value: Rc<dyn Any>,
+  f: impl FnOnce() -> R,
+) -> R {
+  CONTEXT_STACK.with(|stack| stack.borrow_mut().push((context, value)));
+
+  let _guard = ContextGuard;
+  f()
+}
+
+fn find_context(context: usize) -> Option<Rc<dyn Any>> {
+  CONTEXT_STACK.with(|stack| {
+    stack
+      .borrow()
+      .iter()
+      .rev()
+      .find(|(id, _)| *id == context)
+      .map(|(_, value)| value.clone())
+  })
+}
+
+/// Returns the value of the closest provider of the given context and
+/// subscribes the rendering component to it.
+pub(crate) fn read_context(context: usize) -> Option<Rc<dyn Any>> {
+  let value = find_context(context);
+
+  CURRENT_FRAME.with(|frame| {
+    if let Some(frame) = frame.borrow().as_ref() {
+      frame
+        .instance
+        .contexts
+        .borrow_mut()
+        .push((context, value.clone()));
+    }
+  });
+
+  value
+}
@@ -0,0 +1,63 @@
+use std::{
+  cell::{Cell, RefCell},
+  collections::{HashMap, VecDeque},
+  future::Future,
+  pin::Pin,
+  sync::Arc,
+  task::{Context, Wake, Waker},
+};
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+  static TASKS: RefCell<HashMap<usize, Task>> = RefCell::new(HashMap::new());
+  static READY: RefCell<VecDeque<usize>> = const { RefCell::new(VecDeque::new()) };
+  static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Requeues the task with the given id. Tasks are bound to the thread that has
+/// spawned them, so they must be woken on the same thread.
+struct TaskWaker(usize);
+
+impl Wake for TaskWaker {
+  fn wake(self: Arc<Self>) {
+    READY.with(|ready| ready.borrow_mut().push_back(self.0));
+  }
+}
+
+/// Spawns the given future on the current thread. It will be polled by
+/// [`act()`](super::act()).
+pub(crate) fn spawn_local(future: impl Future<Output = ()> + 'static) {
+  let id = NEXT_ID.with(|next_id| next_id.replace(next_id.get() + 1));
+
+  TASKS.with(|tasks| tasks.borrow_mut().insert(id, Box::pin(future)));
+  READY.with(|ready| ready.borrow_mut().push_back(id));
+}
+
+/// Polls all woken tasks until none of them can make progress. Returns whether
+/// any task has been polled.
+pub(crate) fn run_until_stalled() -> bool {
+  let mut did_work = false;
+
+  while let Some(id) = READY.with(|ready| ready.borrow_mut().pop_front()) {
+    // Tasks might have been woken multiple times or completed already
+    let Some(mut task) = TASKS.with(|tasks| tasks.borrow_mut().remove(&id))
+    else {
+      continue;
+    };
+
+    let waker = Waker::from(Arc::new(TaskWaker(id)));
+    did_work = true;
+
+    // Tasks are not borrowed while polling, so they can spawn other tasks
+    if task
+      .as_mut()
+      .poll(&mut Context::from_waker(&waker))
+      .is_pending()
+    {
+      TASKS.with(|tasks| tasks.borrow_mut().insert(id, task));
+    }
+  }
+
+  did_work
+}
@@ -0,0 +1,366 @@
+//! This module provides a test renderer to unit test components natively with
+//! `cargo test`, without a browser or JS runtime.
+//!
+//! Requires the `testing` feature which also enables the `native-runtime`
+//! feature, and a target without JS, see [`ssr`](crate::ssr). Unlike
+//! [`ssr::render_to_string()`](crate::ssr::render_to_string()), components are
+//! mounted: state updates rerender components, effects run and clean up, and
+//! context values are provided just like in React.
+//!
+//! Wrap code that updates components in [`act()`], so all updates and effects
+//! are applied before making assertions. [`render()`] and
+//! [`TestElement::fire()`] do this already. With the `futures` feature,
+//! components that suspend, e.g. with
+//! [`use_resource()`](crate::hooks::use_resource()), show the fallback of the
+//! nearest [`Suspense`](crate::Suspense) until `act()` has driven their
+//! futures.
+//!
+//! # Example
+//!
+//! ```
+//! # use wasm_react::{*, hooks::*, testing::*};
+//! use web_sys::MouseEvent;
+//!
+//! struct Counter;
+//!
+//! impl Component for Counter {
+//!   fn render(&self) -> VNode {
+//!     let counter = use_state(|| 0);
+//!     let value = *counter.value();
+//!
+//!     h!(div).build((
+//!       h!(span[#"value"]).build(value),
+//!       h!(button)
+//!         .on_click(&Callback::new({
+//!           clones!(mut counter);
+//!           move |_| counter.set(|c| c + 1)
+//!         }))
+//!         .build("Increment"),
+//!     ))
+//!   }
+//! }
+//!
+//! let root = render(Counter.build());
+//! assert_eq!(root.get(By::Id("value")).text_content(), "0");
+//!
+//! root
+//!   .get(By::Text("Increment"))
+//!   .fire("onClick", fake_event::<MouseEvent>());
+//! assert_eq!(root.get(By::Id("value")).text_content(), "1");
+//! ```
+
+#[cfg(feature = "futures")]
+mod executor;
+mod query;
+mod renderer;
+
+#[cfg(feature = "futures")]
+pub(crate) use executor::spawn_local;
+pub use query::*;
+#[cfg(feature = "futures")]
+pub(crate) use renderer::suspend;
+
+use crate::{
+  ssr::{Effect, Node, TreeContext},
+  VNode,
+};
+use renderer::{Mounted, Renderer};
+use std::{
+  cell::{Cell, RefCell},
+  fmt::Debug,
+  rc::{Rc, Weak},
+};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Limits the number of consecutive rerenders to detect infinite loops.
+const MAX_UPDATE_DEPTH: usize = 50;
+
+thread_local! {
+  static ACT_DEPTH: Cell<usize> = const { Cell::new(0) };
+  static ROOTS: RefCell<Vec<Weak<RootInner>>> =
+    const { RefCell::new(Vec::new()) };
+}
+
+fn is_acting() -> bool {
+  ACT_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Decrements the act depth even if the closure panics.
+struct ActGuard;
+
+impl ActGuard {
+  fn new() -> Self {
+    ACT_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    ActGuard
+  }
+}
+
+impl Drop for ActGuard {
+  fn drop(&mut self) {
+    ACT_DEPTH.with(|depth| depth.set(depth.get() - 1));
+  }
+}
+
+/// Calls the given closure and applies all state updates it has caused
+/// afterwards, including updates caused by effects, until there is nothing
+/// left to do.
+///
+/// Outside of `act()`, state updates rerender synchronously, but effects
+/// scheduled with [`use_effect()`](crate::hooks::use_effect()) won't run until
+/// the next `act()` or [`TestRoot::flush_effects()`]. The same applies to
+/// futures spawned by async effects with the `futures` feature.
+///
+/// See [React documentation](https://react.dev/reference/react/act).
+///
+/// # Example
+///
+/// ```
+/// # use wasm_react::{*, hooks::*, testing::*};
+/// # fn f(mut state: State<i32>) {
+/// act(|| {
+///   // The component will only rerender once
+///   state.set(|x| x + 1);
+///   state.set(|x| x + 1);
+/// });
+/// # }
+/// ```
+pub fn act<R>(f: impl FnOnce() -> R) -> R {
+  let result = {
+    let _guard = ActGuard::new();
+    f()
+  };
+
+  if !is_acting() {
+    flush();
+  }
+
+  result
+}
+
+/// Renders pending updates and runs pending effects of all roots.
+fn flush() {
+  let mut depth = 0;
+
+  loop {
+    let roots = ROOTS.with(|roots| {
+      let mut roots = roots.borrow_mut();
+      roots.retain(|root| root.strong_count() > 0);
+      roots.iter().filter_map(Weak::upgrade).collect::<Vec<_>>()
+    });
+
+    let mut did_work = false;
+
+    for root in &roots {
+      if root.pending.get() {
+        root.render(None);
+        did_work = true;
+      }
+
+      let effects = root.passive_effects.take();
+
+      if !effects.is_empty() {
+        // Batch updates caused by effects
+        let _guard = ActGuard::new();
+
+        for effect in effects {
+          (effect.run)();
+        }
+
+        did_work = true;
+      }
+    }
+
+    #[cfg(feature = "futures")]
+    {
+      // Batch updates caused by futures
+      let _guard = ActGuard::new();
+
+      if executor::run_until_stalled() {
+        did_work = true;
+
+        // Completed futures might resolve suspended components
+        for root in &roots {
+          if root.tree.borrow().is_suspended() {
+            root.retry.set(true);
+            root.pending.set(true);
+          }
+        }
+      }
+    }
+
+    if !did_work {
+      break;
+    }
+
+    depth += 1;
+    assert!(depth <= MAX_UPDATE_DEPTH, "maximum update depth exceeded");
+  }
+}
+
+/// Returns a placeholder for an event of type `T`, e.g.
+/// [`MouseEvent`](web_sys::MouseEvent), to be passed to
+/// [`TestElement::fire()`].
+///
+/// DOM events cannot be created natively, so the event handler must not access
+/// the event, otherwise it will panic.
+pub fn fake_event<T: JsCast>() -> T {
+  JsValue::UNDEFINED.unchecked_into()
+}
+
+#[derive(Default)]
+struct RootInner {
+  tree: RefCell<Mounted>,
+  passive_effects: RefCell<Vec<Effect>>,
+  pending: Cell<bool>,
+  /// Whether suspended boundaries should be rendered again.
+  retry: Cell<bool>,
+  rendering: Cell<bool>,
+}
+
+/// Resets the rendering state even if rendering panics.
+struct RenderingGuard<'a>(&'a RootInner);
+
+impl Drop for RenderingGuard<'_> {
+  fn drop(&mut self) {
+    self.0.rendering.set(false);
+  }
+}
+
+impl RootInner {
+  fn schedule(self: &Rc<Self>) {
+    self.pending.set(true);
+
+    if !self.rendering.get() && !is_acting() {
+      self.render(None);
+    }
+  }
+
+  /// Renders the given node or rerenders updated components, and runs layout
+  /// effects until there are no more updates.
+  fn render(self: &Rc<Self>, node: Option<&Node>) {
+    self.rendering.set(true);
+    let _guard = RenderingGuard(self);
+
+    if let Some(node) = node {
+      self.commit(|renderer, tree| {
+        renderer.reconcile(tree, node, &TreeContext::root())
+      });
+    }
+
+    let mut depth = 0;
+
+    while self.pending.replace(false) {
+      depth += 1;
+      assert!(depth <= MAX_UPDATE_DEPTH, "too many rerenders");
+
+      self.commit(|renderer, tree| renderer.update(tree));
+    }
+  }
+
+  fn commit(
+    self: &Rc<Self>,
+    f: impl FnOnce(&mut Renderer, Mounted) -> Mounted,
+  ) {
+    let root = Rc::downgrade(self);
+    let mut renderer = Renderer {
+      on_update: Rc::new(move || {
+        if let Some(root) = root.upgrade() {
+          root.schedule();
+        }
+      }),
+      layout_effects: Vec::new(),
+      passive_effects: Vec::new(),
+      retry: self.retry.replace(false),
+    };
+
+    let tree = f(&mut renderer, self.tree.take());
+    *self.tree.borrow_mut() = tree;
+
+    for effect in renderer.layout_effects {
+      (effect.run)();
+    }
+
+    self
+      .passive_effects
+      .borrow_mut()
+      .extend(renderer.passive_effects);
+  }
+}
+
+/// A root of the test renderer returned by [`render()`].
+pub struct TestRoot(Rc<RootInner>);
+
+/// Mounts the given node into a new [`TestRoot`] inside of [`act()`].
+pub fn render(children: impl Into<VNode>) -> TestRoot {
+  let root = TestRoot(Rc::new(RootInner::default()));
+
+  ROOTS.with(|roots| roots.borrow_mut().push(Rc::downgrade(&root.0)));
+  root.rerender(children);
+  root
+}
+
+impl TestRoot {
+  /// Renders the given node inside of [`act()`], updating the existing tree.
+  /// Components of the same type and key at the same position keep their
+  /// state.
+  pub fn rerender(&self, children: impl Into<VNode>) {
+    let node = Node::from(children.into());
+
+    act(|| self.0.render(Some(&node)));
+  }
+
+  /// Runs pending effects and applies all resulting updates. Equivalent to
+  /// `act(|| {})`.
+  pub fn flush_effects(&self) {
+    act(|| {});
+  }
+
+  /// Unmounts the tree inside of [`act()`], running all effect clean-ups.
+  pub fn unmount(self) {
+    act(|| {
+      self.0.commit(|renderer, tree| {
+        renderer.unmount(tree);
+        Mounted::Empty
+      })
+    });
+  }
+
+  /// Returns the rendered top-level host nodes.
+  pub fn children(&self) -> Vec<TestNode> {
+    let mut result = Vec::new();
+    self.0.tree.borrow().collect_host_nodes(&mut result);
+    result
+  }
+
+  /// Returns the concatenated text of all rendered nodes.
+  pub fn text_content(&self) -> String {
+    self.children().iter().map(TestNode::text_content).collect()
+  }
+
+  /// Returns all elements matching the given query in document order.
+  pub fn query_all(&self, by: By) -> Vec<TestElement> {
+    let mut result = Vec::new();
+    query::query_all(&self.children(), by, &mut result);
+    result
+  }
+
+  /// Returns the first element matching the given query.
+  pub fn query(&self, by: By) -> Option<TestElement> {
+    query::query(&self.children(), by)
+  }
+
+  /// Returns the only element matching the given query.
+  ///
+  /// # Panics
+  ///
+  /// Panics if there is no or more than one matching element.
+  pub fn get(&self, by: By) -> TestElement {
+    query::get(&self.children(), by)
+  }
+}
+
+impl Debug for TestRoot {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("TestRoot").field(&self.children()).finish()
+  }
+}
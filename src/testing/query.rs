@@ -0,0 +1,178 @@
+use super::act;
+use crate::{
+  props::{PropValue, Props},
+  Callback,
+};
+use std::rc::Rc;
+use wasm_bindgen::UnwrapThrowExt;
+
+/// Specifies which elements a query of the test renderer should match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum By<'a> {
+  /// Matches elements with the given tag name, e.g. `By::Tag("button")`.
+  Tag(&'a str),
+  /// Matches elements with the given `id` attribute.
+  Id(&'a str),
+  /// Matches elements which have the given class in their `className`.
+  Class(&'a str),
+  /// Matches elements whose own text nodes, trimmed, equal the given text.
+  Text(&'a str),
+}
+
+impl By<'_> {
+  fn matches(&self, element: &TestElement) -> bool {
+    match *self {
+      By::Tag(tag) => element.tag.eq_ignore_ascii_case(tag),
+      By::Id(id) => element.prop_string("id").is_some_and(|x| x == id),
+      By::Class(class) => element
+        .prop_string("className")
+        .is_some_and(|x| x.split_ascii_whitespace().any(|x| x == class)),
+      By::Text(text) => element.own_text().trim() == text,
+    }
+  }
+}
+
+/// A rendered host node, i.e. an element or text node.
+#[derive(Debug, Clone)]
+pub enum TestNode {
+  /// A text node.
+  Text(Rc<str>),
+  /// An HTML element.
+  Element(TestElement),
+}
+
+impl TestNode {
+  /// Returns the concatenated text of the node and all its descendants.
+  pub fn text_content(&self) -> String {
+    match self {
+      TestNode::Text(text) => text.to_string(),
+      TestNode::Element(element) => element.text_content(),
+    }
+  }
+}
+
+/// A snapshot of a rendered HTML element.
+///
+/// The snapshot doesn't change when the component rerenders, so query the
+/// [`TestRoot`](super::TestRoot) again after updates.
+#[derive(Debug, Clone)]
+pub struct TestElement {
+  pub(super) tag: Rc<str>,
+  pub(super) props: Props,
+  pub(super) children: Vec<TestNode>,
+}
+
+impl TestElement {
+  /// Returns the tag name of the element.
+  pub fn tag(&self) -> &str {
+    &self.tag
+  }
+
+  /// Returns the value of the given prop, e.g. `"className"` or `"onClick"`.
+  pub fn prop(&self, name: &str) -> Option<&PropValue> {
+    self.props.get(name)
+  }
+
+  /// Returns the child nodes of the element.
+  pub fn children(&self) -> &[TestNode] {
+    &self.children
+  }
+
+  /// Returns the concatenated text of all descendants.
+  pub fn text_content(&self) -> String {
+    self.children.iter().map(TestNode::text_content).collect()
+  }
+
+  /// Calls the event handler of the given prop, e.g. `"onClick"`, with the
+  /// given event inside of [`act()`].
+  ///
+  /// Use [`fake_event()`](super::fake_event()) to pass an event of a DOM
+  /// event type.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the element has no event handler with the given name or if it
+  /// expects an event of a different type.
+  pub fn fire<T: 'static>(&self, name: &str, event: T) {
+    let callback = match self.prop(name) {
+      Some(PropValue::Function(f)) => f
+        .downcast_ref::<Callback<T>>()
+        .expect_throw("mismatched event type")
+        .clone(),
+      _ => panic!("element <{}> has no event handler `{name}`", self.tag),
+    };
+
+    act(|| callback.call(event));
+  }
+
+  /// Returns all descendants matching the given query in document order.
+  pub fn query_all(&self, by: By) -> Vec<TestElement> {
+    let mut result = Vec::new();
+    query_all(&self.children, by, &mut result);
+    result
+  }
+
+  /// Returns the first descendant matching the given query.
+  pub fn query(&self, by: By) -> Option<TestElement> {
+    query(&self.children, by)
+  }
+
+  /// Returns the only descendant matching the given query.
+  ///
+  /// # Panics
+  ///
+  /// Panics if there is no or more than one matching element.
+  pub fn get(&self, by: By) -> TestElement {
+    get(&self.children, by)
+  }
+
+  fn prop_string(&self, name: &str) -> Option<String> {
+    self.prop(name).and_then(PropValue::to_js_string)
+  }
+
+  fn own_text(&self) -> String {
+    self
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        TestNode::Text(text) => Some(&**text),
+        TestNode::Element(_) => None,
+      })
+      .collect()
+  }
+}
+
+pub(super) fn query_all(
+  nodes: &[TestNode],
+  by: By,
+  result: &mut Vec<TestElement>,
+) {
+  for node in nodes {
+    if let TestNode::Element(element) = node {
+      if by.matches(element) {
+        result.push(element.clone());
+      }
+
+      query_all(&element.children, by, result);
+    }
+  }
+}
+
+pub(super) fn query(nodes: &[TestNode], by: By) -> Option<TestElement> {
+  nodes.iter().find_map(|node| match node {
+    TestNode::Element(element) if by.matches(element) => Some(element.clone()),
+    TestNode::Element(element) => query(&element.children, by),
+    TestNode::Text(_) => None,
+  })
+}
+
+pub(super) fn get(nodes: &[TestNode], by: By) -> TestElement {
+  let mut result = Vec::new();
+  query_all(nodes, by, &mut result);
+
+  match result.len() {
+    1 => result.remove(0),
+    0 => panic!("unable to find an element matching {by:?}"),
+    n => panic!("found {n} elements matching {by:?}"),
+  }
+}
@@ -0,0 +1,555 @@
+use super::{TestElement, TestNode};
+use crate::ssr::{
+  self, ComponentNode, Effect, ElementNode, Instance, Node, NodeKind,
+  OwnedElementType, PropValue, ProviderNode, TreeContext,
+};
+use std::{
+  cell::Cell,
+  panic::{self, AssertUnwindSafe},
+  rc::Rc,
+};
+
+/// The panic payload of a component which suspends.
+pub(crate) struct Suspended;
+
+thread_local! {
+  /// The number of `Suspense` boundaries which are currently rendering.
+  static SUSPENSE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Decrements the suspense depth even if rendering panics.
+struct SuspenseGuard;
+
+impl SuspenseGuard {
+  fn new() -> Self {
+    SUSPENSE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    SuspenseGuard
+  }
+}
+
+impl Drop for SuspenseGuard {
+  fn drop(&mut self) {
+    SUSPENSE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+  }
+}
+
+/// Suspends the rendering component, so the nearest `Suspense` boundary shows
+/// its fallback until pending futures have been driven by `act()`.
+#[cfg(feature = "futures")]
+pub(crate) fn suspend() -> ! {
+  if SUSPENSE_DEPTH.with(|depth| depth.get()) == 0 {
+    panic!("a component suspended outside of a `Suspense` boundary");
+  }
+
+  // Doesn't invoke the panic hook
+  panic::resume_unwind(Box::new(Suspended))
+}
+
+/// The mounted counterpart of [`Node`] which keeps the instances of
+/// components alive across renders.
+#[derive(Default, Clone)]
+pub(crate) enum Mounted {
+  #[default]
+  Empty,
+  Text(Rc<str>),
+  Array(Vec<Mounted>),
+  Element(Rc<ElementNode>, Box<Mounted>),
+  Component(Box<MountedComponent>),
+  Provider(Rc<ProviderNode>, Box<Mounted>),
+  Suspense(Box<MountedSuspense>),
+}
+
+#[derive(Clone)]
+pub(crate) struct MountedSuspense {
+  element: Rc<ElementNode>,
+  tree_context: TreeContext,
+  /// The mounted children which are hidden while the fallback is shown.
+  child: Mounted,
+  /// The mounted fallback if a child has suspended.
+  fallback: Option<Mounted>,
+}
+
+#[derive(Clone)]
+pub(crate) struct MountedComponent {
+  node: Rc<ComponentNode>,
+  instance: Rc<Instance>,
+  tree_context: TreeContext,
+  child: Mounted,
+}
+
+fn normalize_key(key: Option<&PropValue>) -> Option<String> {
+  key.and_then(PropValue::to_js_string)
+}
+
+fn node_key(node: &Node) -> Option<String> {
+  match &node.0 {
+    NodeKind::Element(element) => normalize_key(element.props.get("key")),
+    NodeKind::Component(component) => normalize_key(component.key.as_ref()),
+    _ => None,
+  }
+}
+
+impl Mounted {
+  fn key(&self) -> Option<String> {
+    match self {
+      Mounted::Element(element, _) => normalize_key(element.props.get("key")),
+      Mounted::Component(component) => {
+        normalize_key(component.node.key.as_ref())
+      }
+      Mounted::Suspense(suspense) => {
+        normalize_key(suspense.element.props.get("key"))
+      }
+      _ => None,
+    }
+  }
+
+  /// Returns whether the tree contains a `Suspense` boundary which shows its
+  /// fallback.
+  pub fn is_suspended(&self) -> bool {
+    match self {
+      Mounted::Empty | Mounted::Text(_) => false,
+      Mounted::Array(children) => children.iter().any(Mounted::is_suspended),
+      Mounted::Element(_, child) | Mounted::Provider(_, child) => {
+        child.is_suspended()
+      }
+      Mounted::Component(component) => component.child.is_suspended(),
+      Mounted::Suspense(suspense) => {
+        suspense.fallback.is_some() || suspense.child.is_suspended()
+      }
+    }
+  }
+
+  /// Appends the host nodes of the tree to the given vector.
+  pub fn collect_host_nodes(&self, result: &mut Vec<TestNode>) {
+    match self {
+      Mounted::Empty => {}
+      Mounted::Text(text) => {
+        if !text.is_empty() {
+          result.push(TestNode::Text(text.clone()));
+        }
+      }
+      Mounted::Array(children) => {
+        for child in children {
+          child.collect_host_nodes(result);
+        }
+      }
+      Mounted::Element(element, child) => match &element.typ {
+        OwnedElementType::Html(tag) => {
+          let mut children = Vec::new();
+          child.collect_host_nodes(&mut children);
+
+          result.push(TestNode::Element(TestElement {
+            tag: tag.clone(),
+            props: element.props.clone(),
+            children,
+          }));
+        }
+        OwnedElementType::Transparent => child.collect_host_nodes(result),
+        OwnedElementType::Suspense => child.collect_host_nodes(result),
+        // Components imported from JS cannot be rendered natively
+        OwnedElementType::Unsupported => {}
+      },
+      Mounted::Component(component) => {
+        component.child.collect_host_nodes(result)
+      }
+      Mounted::Provider(_, child) => child.collect_host_nodes(result),
+      Mounted::Suspense(suspense) => suspense
+        .fallback
+        .as_ref()
+        .unwrap_or(&suspense.child)
+        .collect_host_nodes(result),
+    }
+  }
+}
+
+/// Reconciles the mounted tree with new nodes and collects the effects of all
+/// rendered components.
+pub(crate) struct Renderer {
+  pub on_update: Rc<dyn Fn()>,
+  pub layout_effects: Vec<Effect>,
+  pub passive_effects: Vec<Effect>,
+  /// Whether suspended boundaries should try to render their children again.
+  pub retry: bool,
+}
+
+impl Renderer {
+  /// Updates the given mounted tree to match `node`, reusing component
+  /// instances of the same type and key.
+  pub fn reconcile(
+    &mut self,
+    old: Mounted,
+    node: &Node,
+    tree_context: &TreeContext,
+  ) -> Mounted {
+    match &node.0 {
+      NodeKind::Empty => {
+        self.unmount(old);
+        Mounted::Empty
+      }
+      NodeKind::Text(text) => {
+        self.unmount(old);
+        Mounted::Text(text.clone())
+      }
+      NodeKind::Array(nodes) => {
+        let mut old_children = match old {
+          Mounted::Array(children) => {
+            children.into_iter().map(Some).collect::<Vec<_>>()
+          }
+          old => {
+            self.unmount(old);
+            Vec::new()
+          }
+        };
+
+        let children = nodes
+          .iter()
+          .enumerate()
+          .map(|(i, node)| {
+            let key = node_key(node);
+            let old_child = match &key {
+              Some(_) => old_children
+                .iter_mut()
+                .find(|child| {
+                  child.as_ref().is_some_and(|child| child.key() == key)
+                })
+                .and_then(Option::take),
+              None => old_children
+                .get_mut(i)
+                .filter(|child| {
+                  child.as_ref().is_some_and(|child| child.key().is_none())
+                })
+                .and_then(Option::take),
+            };
+
+            self.reconcile(
+              old_child.unwrap_or_default(),
+              node,
+              &tree_context.fork(nodes.len(), i),
+            )
+          })
+          .collect();
+
+        for old_child in old_children.into_iter().flatten() {
+          self.unmount(old_child);
+        }
+
+        Mounted::Array(children)
+      }
+      NodeKind::Element(element)
+        if element.typ == OwnedElementType::Suspense =>
+      {
+        let (old_child, fallback) = match old {
+          Mounted::Suspense(suspense)
+            if normalize_key(suspense.element.props.get("key"))
+              == node_key(node) =>
+          {
+            (suspense.child, suspense.fallback)
+          }
+          old => {
+            self.unmount(old);
+            (Mounted::Empty, None)
+          }
+        };
+
+        self.render_suspense(
+          element.clone(),
+          old_child,
+          fallback,
+          tree_context.clone(),
+        )
+      }
+      NodeKind::Element(element) => {
+        let old_child = match old {
+          Mounted::Element(old_element, child)
+            if old_element.typ == element.typ
+              && normalize_key(old_element.props.get("key"))
+                == node_key(node) =>
+          {
+            *child
+          }
+          old => {
+            self.unmount(old);
+            Mounted::Empty
+          }
+        };
+
+        Mounted::Element(
+          element.clone(),
+          Box::new(self.reconcile(old_child, &element.children, tree_context)),
+        )
+      }
+      NodeKind::Component(component) => {
+        let (instance, old_child) = match old {
+          Mounted::Component(mut old_component)
+            if old_component.node.name == component.name
+              && normalize_key(old_component.node.key.as_ref())
+                == node_key(node) =>
+          {
+            if component.memo_eq(&old_component.node)
+              && !old_component.instance.needs_render()
+            {
+              // Skip rendering memoized components if props haven't changed,
+              // but descendants might still need to update
+              old_component.child = self.update(old_component.child);
+
+              return Mounted::Component(old_component);
+            }
+
+            (old_component.instance, old_component.child)
+          }
+          old => {
+            self.unmount(old);
+            (Instance::new(Some(self.on_update.clone())), Mounted::Empty)
+          }
+        };
+
+        Mounted::Component(self.render_component(MountedComponent {
+          node: component.clone(),
+          instance,
+          tree_context: tree_context.clone(),
+          child: old_child,
+        }))
+      }
+      NodeKind::Provider(provider) => {
+        let old_child = match old {
+          Mounted::Provider(old_provider, child)
+            if old_provider.context == provider.context =>
+          {
+            *child
+          }
+          old => {
+            self.unmount(old);
+            Mounted::Empty
+          }
+        };
+
+        let child = self.with_provider(provider, |renderer| {
+          renderer.reconcile(old_child, &provider.children, tree_context)
+        });
+
+        Mounted::Provider(provider.clone(), Box::new(child))
+      }
+    }
+  }
+
+  /// Rerenders all components in the given tree whose state has been updated.
+  pub fn update(&mut self, mounted: Mounted) -> Mounted {
+    match mounted {
+      Mounted::Array(children) => Mounted::Array(
+        children
+          .into_iter()
+          .map(|child| self.update(child))
+          .collect(),
+      ),
+      Mounted::Element(element, child) => {
+        Mounted::Element(element, Box::new(self.update(*child)))
+      }
+      Mounted::Component(mut component) => {
+        if component.instance.needs_render() {
+          Mounted::Component(self.render_component(*component))
+        } else {
+          component.child = self.update(component.child);
+          Mounted::Component(component)
+        }
+      }
+      Mounted::Provider(provider, child) => {
+        let child =
+          self.with_provider(&provider, |renderer| renderer.update(*child));
+
+        Mounted::Provider(provider, Box::new(child))
+      }
+      Mounted::Suspense(suspense) => {
+        let MountedSuspense {
+          element,
+          tree_context,
+          child,
+          fallback,
+        } = *suspense;
+
+        match fallback {
+          Some(fallback) if !self.retry => {
+            Mounted::Suspense(Box::new(MountedSuspense {
+              element,
+              tree_context,
+              child,
+              fallback: Some(self.update(fallback)),
+            }))
+          }
+          Some(fallback) => {
+            self.render_suspense(element, child, Some(fallback), tree_context)
+          }
+          None => {
+            let snapshot = child.clone();
+
+            self.suspend_on_unwind(
+              element,
+              snapshot,
+              None,
+              tree_context,
+              |renderer| renderer.update(child),
+            )
+          }
+        }
+      }
+      mounted => mounted,
+    }
+  }
+
+  /// Runs the clean-up functions of all effects in the given tree.
+  pub fn unmount(&mut self, mounted: Mounted) {
+    match mounted {
+      Mounted::Array(children) => {
+        for child in children {
+          self.unmount(child);
+        }
+      }
+      Mounted::Element(_, child) | Mounted::Provider(_, child) => {
+        self.unmount(*child)
+      }
+      Mounted::Component(component) => {
+        component.instance.unmount();
+        self.unmount(component.child);
+      }
+      Mounted::Suspense(suspense) => {
+        self.unmount(suspense.child);
+
+        if let Some(fallback) = suspense.fallback {
+          self.unmount(fallback);
+        }
+      }
+      Mounted::Empty | Mounted::Text(_) => {}
+    }
+  }
+
+  /// Renders the children of a `Suspense` boundary, or its fallback if one of
+  /// the children suspends.
+  fn render_suspense(
+    &mut self,
+    element: Rc<ElementNode>,
+    old_child: Mounted,
+    fallback: Option<Mounted>,
+    tree_context: TreeContext,
+  ) -> Mounted {
+    let snapshot = old_child.clone();
+    let children = element.children.clone();
+    let context = tree_context.clone();
+
+    self.suspend_on_unwind(
+      element,
+      snapshot,
+      fallback,
+      tree_context,
+      |renderer| renderer.reconcile(old_child, &children, &context),
+    )
+  }
+
+  /// Calls `render` to render the children of a `Suspense` boundary and shows
+  /// its fallback instead if a child suspends. Like in React, children which
+  /// have been shown already stay mounted, but hidden, as they were before the
+  /// interrupted render.
+  fn suspend_on_unwind(
+    &mut self,
+    element: Rc<ElementNode>,
+    snapshot: Mounted,
+    fallback: Option<Mounted>,
+    tree_context: TreeContext,
+    render: impl FnOnce(&mut Self) -> Mounted,
+  ) -> Mounted {
+    let layout_effects = self.layout_effects.len();
+    let passive_effects = self.passive_effects.len();
+    let result = {
+      let _guard = SuspenseGuard::new();
+
+      panic::catch_unwind(AssertUnwindSafe(|| render(self)))
+    };
+
+    match result {
+      Ok(child) => {
+        if let Some(fallback) = fallback {
+          self.unmount(fallback);
+        }
+
+        Mounted::Suspense(Box::new(MountedSuspense {
+          element,
+          tree_context,
+          child,
+          fallback: None,
+        }))
+      }
+      Err(payload) if payload.is::<Suspended>() => {
+        // Discard the effects of the interrupted render
+        self.layout_effects.truncate(layout_effects);
+        self.passive_effects.truncate(passive_effects);
+
+        let fallback_node = match element.props.get("fallback") {
+          Some(PropValue::Node(fallback)) => Node::from(fallback.clone()),
+          _ => Node::empty(),
+        };
+        let fallback = self.reconcile(
+          fallback.unwrap_or_default(),
+          &fallback_node,
+          &tree_context,
+        );
+
+        Mounted::Suspense(Box::new(MountedSuspense {
+          element,
+          tree_context,
+          child: snapshot,
+          fallback: Some(fallback),
+        }))
+      }
+      Err(payload) => panic::resume_unwind(payload),
+    }
+  }
+
+  fn render_component(
+    &mut self,
+    component: MountedComponent,
+  ) -> Box<MountedComponent> {
+    let MountedComponent {
+      node,
+      instance,
+      tree_context,
+      child,
+    } = component;
+
+    let rendered =
+      ssr::render_component(&*node.component, &instance, &tree_context);
+    let children = Node::from(rendered.children);
+    let child = if rendered.did_use_id {
+      // React forks the tree context to keep ids of children unique
+      self.reconcile(child, &children, &tree_context.fork(1, 0))
+    } else {
+      self.reconcile(child, &children, &tree_context)
+    };
+
+    // Effects of children run before the effects of their parents
+    for effect in rendered.effects {
+      if effect.layout {
+        self.layout_effects.push(effect);
+      } else {
+        self.passive_effects.push(effect);
+      }
+    }
+
+    Box::new(MountedComponent {
+      node,
+      instance,
+      tree_context,
+      child,
+    })
+  }
+
+  fn with_provider<R>(
+    &mut self,
+    provider: &ProviderNode,
+    f: impl FnOnce(&mut Self) -> R,
+  ) -> R {
+    match &provider.value {
+      Some(value) => {
+        ssr::with_context(provider.context, value.clone(), || f(self))
+      }
+      None => f(self),
+    }
+  }
+}
@@ -1,6 +1,11 @@
 #![allow(non_snake_case)]
 
-use js_sys::{Array, JsString};
+#[cfg(native_runtime)]
+use crate::{
+  props::PropValue,
+  ssr::{self, js_number_to_string, Node},
+};
+use js_sys::{Array, JsString, Reflect};
 use wasm_bindgen::JsValue;
 
 /// Represents a node in the virtual DOM of React.
@@ -11,12 +16,70 @@ pub enum VNode {
   Single(JsValue),
   /// Represents an array of virtual nodes.
   List(Array),
+  /// Represents a single node of the native element tree, which replaces
+  /// React elements with the `native-runtime` feature on non-JS targets.
+  #[cfg(native_runtime)]
+  Native(Node),
+  /// Represents an array of nodes of the native element tree.
+  #[cfg(native_runtime)]
+  NativeList(Vec<Node>),
 }
 
 impl VNode {
   /// Creates an empty node that doesn't render anything.
   pub fn new() -> VNode {
-    VNode::Single(JsValue::null())
+    #[cfg(not(native_runtime))]
+    {
+      VNode::Single(JsValue::null())
+    }
+    #[cfg(native_runtime)]
+    {
+      VNode::Native(Node::empty())
+    }
+  }
+
+  /// Returns the `children` prop of the given JS props object as a node, e.g.
+  /// to render the children passed to an exported component.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use wasm_react::*;
+  /// # use wasm_bindgen::prelude::*;
+  /// pub struct Card {
+  ///   children: VNode,
+  /// }
+  ///
+  /// impl Component for Card {
+  ///   fn render(&self) -> VNode {
+  ///     h!(div[."card"]).build(self.children.clone())
+  ///   }
+  /// }
+  ///
+  /// impl TryFrom<JsValue> for Card {
+  ///   type Error = JsValue;
+  ///
+  ///   fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+  ///     Ok(Card {
+  ///       children: VNode::from_js_children(&value),
+  ///     })
+  ///   }
+  /// }
+  ///
+  /// export_components! { Card }
+  /// ```
+  pub fn from_js_children(props: &JsValue) -> VNode {
+    let children =
+      Reflect::get(props, &"children".into()).unwrap_or(JsValue::UNDEFINED);
+
+    if children.is_undefined() {
+      VNode::new()
+    } else if Array::is_array(&children) {
+      // React freezes children arrays, so we need a copy to push onto
+      VNode::List(Array::from(&children))
+    } else {
+      VNode::Single(children)
+    }
   }
 
   /// Adds the given node to the list.
@@ -37,6 +100,23 @@ impl VNode {
       VNode::List(arr) => {
         arr.push(node.as_ref());
       }
+      #[cfg(native_runtime)]
+      VNode::Native(x) => {
+        *self = VNode::NativeList({
+          let mut list = Vec::new();
+
+          if !x.is_empty() {
+            list.push(x.clone());
+          }
+
+          list.push(node.clone().into());
+          list
+        });
+      }
+      #[cfg(native_runtime)]
+      VNode::NativeList(list) => {
+        list.push(node.clone().into());
+      }
     }
   }
 }
@@ -52,6 +132,10 @@ impl AsRef<JsValue> for VNode {
     match self {
       VNode::Single(x) => x,
       VNode::List(x) => x,
+      #[cfg(native_runtime)]
+      VNode::Native(_) | VNode::NativeList(_) => {
+        ssr::unsupported("converting native nodes into JS values")
+      }
     }
   }
 }
@@ -61,10 +145,21 @@ impl From<VNode> for JsValue {
     match value {
       VNode::Single(x) => x,
       VNode::List(x) => x.into(),
+      #[cfg(native_runtime)]
+      VNode::Native(_) | VNode::NativeList(_) => {
+        ssr::unsupported("converting native nodes into JS values")
+      }
     }
   }
 }
 
+#[cfg(native_runtime)]
+impl From<VNode> for PropValue {
+  fn from(value: VNode) -> Self {
+    PropValue::Node(value)
+  }
+}
+
 impl<T: Into<VNode>> From<Option<T>> for VNode {
   fn from(value: Option<T>) -> Self {
     value.map(|value| value.into()).unwrap_or_default()
@@ -104,6 +199,7 @@ macro_rules! impl_into_vnode {
 }
 
 // Implement `Into<VNode>` for as many `Display` types as possible
+#[cfg(not(native_runtime))]
 impl_into_vnode! {
   &str, String, JsString,
   f32, f64,
@@ -111,6 +207,47 @@ impl_into_vnode! {
   u8, u16, u32, u64, u128, usize,
 }
 
+#[cfg(native_runtime)]
+macro_rules! impl_into_native_vnode {
+  { strings: $( $S:ty ),*; floats: $( $F:ty ),*; integers: $( $I:ty ),* $(,)? } => {
+    $(
+      impl From<$S> for VNode {
+        fn from(value: $S) -> Self {
+          VNode::Native(Node::text(value))
+        }
+      }
+    )*
+
+    $(
+      impl From<$F> for VNode {
+        fn from(value: $F) -> Self {
+          VNode::Native(Node::text(js_number_to_string(value as f64)))
+        }
+      }
+    )*
+
+    $(
+      impl From<$I> for VNode {
+        fn from(value: $I) -> Self {
+          VNode::Native(Node::text(value.to_string()))
+        }
+      }
+    )*
+  };
+}
+
+#[cfg(native_runtime)]
+impl_into_vnode! { JsString }
+
+#[cfg(native_runtime)]
+impl_into_native_vnode! {
+  strings: &str, String;
+  floats: f32, f64;
+  integers:
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+}
+
 impl From<()> for VNode {
   fn from(_: ()) -> Self {
     VNode::new()
@@ -0,0 +1,52 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::{
+  cell::RefCell,
+  future::Future,
+  pin::Pin,
+  rc::Rc,
+  task::{Context, Poll, Waker},
+};
+
+/// A future which stays pending until it has been opened.
+#[derive(Clone, Default)]
+pub struct Gate(Rc<RefCell<(bool, Option<Waker>)>>);
+
+impl Gate {
+  /// Returns a gate which has been opened already.
+  pub fn opened() -> Self {
+    let gate = Self::default();
+    gate.open();
+    gate
+  }
+
+  /// Lets all futures waiting for the gate complete.
+  pub fn open(&self) {
+    let waker = {
+      let mut state = self.0.borrow_mut();
+      state.0 = true;
+      state.1.take()
+    };
+
+    if let Some(waker) = waker {
+      waker.wake();
+    }
+  }
+}
+
+impl Future for Gate {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+    let mut state = self.0.borrow_mut();
+
+    if state.0 {
+      Poll::Ready(())
+    } else {
+      state.1 = Some(cx.waker().clone());
+      Poll::Pending
+    }
+  }
+}
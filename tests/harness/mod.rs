@@ -0,0 +1,185 @@
+//! Test harness which renders components with the real React runtime and
+//! `react-bindings.js` glue in Node, using a vendored React and `jsdom` as DOM
+//! shim.
+//!
+//! Install the JS dependencies with `npm install` and run the tests with
+//! `npm run test-web`. Install `react@19` and `react-dom@19` to run the tests
+//! against React 19.
+
+#![allow(dead_code)]
+
+use js_sys::Promise;
+use std::future::Future;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+use wasm_react::{Root, VNode, WasmReact};
+use web_sys::Element;
+
+#[wasm_bindgen(module = "/tests/harness/setup.js")]
+extern "C" {
+  #[wasm_bindgen(js_name = getReact)]
+  fn get_react() -> JsValue;
+
+  #[wasm_bindgen(js_name = getReactDom)]
+  fn get_react_dom() -> JsValue;
+
+  #[wasm_bindgen(js_name = getReactVersion)]
+  fn get_react_version() -> String;
+
+  #[wasm_bindgen(js_name = act)]
+  fn act_js(f: &mut dyn FnMut());
+
+  #[wasm_bindgen(js_name = actAsync)]
+  fn act_async_js(promise: &Promise) -> Promise;
+
+  #[wasm_bindgen(js_name = createContainer)]
+  fn create_container() -> Element;
+
+  #[wasm_bindgen(js_name = dispatchEvent)]
+  fn dispatch_event_js(target: &Element, typ: &str);
+
+  #[wasm_bindgen(js_name = flushTimers)]
+  fn flush_timers_js() -> Promise;
+}
+
+/// Registers the React runtimes with `wasm-react`. Subsequent calls are
+/// no-ops.
+pub fn setup() {
+  WasmReact::use_react(&get_react());
+  WasmReact::use_react_dom(&get_react_dom());
+}
+
+/// Calls the given closure inside of React's `act()`, so all updates and
+/// effects are flushed before it returns.
+pub fn act(f: impl FnOnce()) {
+  let mut f = Some(f);
+
+  act_js(&mut || {
+    if let Some(f) = f.take() {
+      f();
+    }
+  });
+}
+
+/// Awaits the given future inside of React's `act()`, so all updates and
+/// effects caused by it are flushed before it returns.
+pub async fn act_async(future: impl Future<Output = ()> + 'static) {
+  let promise = future_to_promise(async move {
+    future.await;
+    Ok(JsValue::UNDEFINED)
+  });
+
+  JsFuture::from(act_async_js(&promise))
+    .await
+    .expect_throw("unable to act");
+}
+
+/// Returns the major version of the React runtime the tests run against.
+pub fn react_major_version() -> u32 {
+  get_react_version()
+    .split('.')
+    .next()
+    .and_then(|major| major.parse().ok())
+    .expect_throw("unable to read React version")
+}
+
+/// Waits for pending timeouts, e.g. the ones which free temporary refs.
+pub async fn flush_timers() {
+  JsFuture::from(flush_timers_js())
+    .await
+    .expect_throw("unable to wait for timers");
+}
+
+/// A React root mounted into a container which is attached to the document.
+pub struct TestContainer {
+  root: Root,
+  container: Element,
+}
+
+impl TestContainer {
+  /// Renders the given node into a new container inside of [`act()`].
+  pub fn render(children: impl Into<VNode>) -> Self {
+    setup();
+
+    let container = create_container();
+    let result = Self {
+      root: Root::create(&container),
+      container,
+    };
+
+    result.rerender(children);
+    result
+  }
+
+  /// Renders the given node into the existing root inside of [`act()`].
+  pub fn rerender(&self, children: impl Into<VNode>) {
+    let children = children.into();
+    act(|| self.root.render(children));
+  }
+
+  /// Unmounts the root inside of [`act()`] and removes the container.
+  pub fn unmount(self) {
+    let Self { root, container } = self;
+
+    act(|| root.unmount());
+    container.remove();
+  }
+
+  /// Returns the container element.
+  pub fn container(&self) -> &Element {
+    &self.container
+  }
+
+  /// Returns the rendered HTML.
+  pub fn inner_html(&self) -> String {
+    self.container.inner_html()
+  }
+
+  /// Returns the rendered text.
+  pub fn text_content(&self) -> String {
+    self.container.text_content().unwrap_or_default()
+  }
+
+  /// Returns the first element matching the given CSS selector.
+  pub fn query(&self, selector: &str) -> Option<Element> {
+    self
+      .container
+      .query_selector(selector)
+      .expect_throw("invalid selector")
+  }
+
+  /// Returns all elements matching the given CSS selector.
+  pub fn query_all(&self, selector: &str) -> Vec<Element> {
+    let nodes = self
+      .container
+      .query_selector_all(selector)
+      .expect_throw("invalid selector");
+
+    (0..nodes.length())
+      .filter_map(|i| nodes.get(i))
+      .filter_map(|node| node.dyn_into::<Element>().ok())
+      .collect()
+  }
+
+  /// Returns the first element matching the given CSS selector.
+  ///
+  /// # Panics
+  ///
+  /// Panics if there is no matching element.
+  pub fn get(&self, selector: &str) -> Element {
+    self
+      .query(selector)
+      .unwrap_or_else(|| panic!("unable to find element `{selector}`"))
+  }
+
+  /// Dispatches a bubbling event of the given type, e.g. `"click"`, on the
+  /// first element matching the given CSS selector inside of [`act()`].
+  pub fn dispatch(&self, selector: &str, typ: &str) {
+    dispatch_event_js(&self.get(selector), typ);
+  }
+
+  /// Clicks the first element matching the given CSS selector.
+  pub fn click(&self, selector: &str) {
+    self.dispatch(selector, "click");
+  }
+}
@@ -0,0 +1,53 @@
+// Installs a DOM shim before React is loaded
+import "global-jsdom/register";
+import React from "react";
+import ReactDOM from "react-dom";
+import ReactDOMClient from "react-dom/client";
+import ReactTestUtils from "react-dom/test-utils";
+
+// Tells React that updates are wrapped in `act()`
+globalThis.IS_REACT_ACT_ENVIRONMENT = true;
+
+export function getReact() {
+  return React;
+}
+
+// React 19 moved `act()` from `react-dom/test-utils` to `react`
+const reactAct = React.act ?? ReactTestUtils.act;
+
+export function getReactDom() {
+  return { ...ReactDOM, ...ReactDOMClient };
+}
+
+export function getReactVersion() {
+  return React.version;
+}
+
+export function act(f) {
+  reactAct(() => {
+    f();
+  });
+}
+
+export function actAsync(promise) {
+  return reactAct(() => promise);
+}
+
+export function createContainer() {
+  const container = document.createElement("div");
+  document.body.appendChild(container);
+  return container;
+}
+
+export function dispatchEvent(target, type) {
+  act(() => {
+    target.dispatchEvent(
+      new window.Event(type, { bubbles: true, cancelable: true })
+    );
+  });
+}
+
+export function flushTimers() {
+  // Temporary refs are freed in a timeout after effects have been cleaned up
+  return new Promise((resolve) => setTimeout(resolve));
+}
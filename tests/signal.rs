@@ -0,0 +1,102 @@
+#![cfg(native_testing)]
+
+use std::cell::Cell;
+use wasm_react::{
+  h, hooks::*, testing::*, Component, PropContainer, Signal, VNode,
+};
+
+thread_local! {
+  static RENDERS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn renders() -> u32 {
+  RENDERS.with(|renders| renders.get())
+}
+
+struct Parity {
+  even: Signal<bool>,
+}
+
+impl Component for Parity {
+  fn render(&self) -> VNode {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    let even = *use_signal_value(&self.even);
+
+    h!(span).build(if even { "even" } else { "odd" })
+  }
+}
+
+#[test]
+fn computed_signals_rerender_only_when_their_value_changes() {
+  let count = Signal::new(0);
+  let even = count.map(|count| count % 2 == 0);
+  let root = render(Parity { even: even.clone() }.build());
+
+  assert_eq!(root.text_content(), "even");
+  assert_eq!(renders(), 1);
+
+  act(|| count.set(|count| count + 2));
+  assert_eq!(renders(), 1);
+
+  act(|| count.set(|count| count + 1));
+  assert_eq!(root.text_content(), "odd");
+  assert_eq!(renders(), 2);
+}
+
+#[test]
+fn computed_signals_changed_before_subscribing_rerender() {
+  let count = Signal::new(0);
+  let even = count.map(|count| count % 2 == 0);
+
+  // The source changes after the first render, but before effects subscribe
+  let root = act(|| {
+    let root = render(Parity { even: even.clone() }.build());
+
+    count.set(|count| count + 1);
+    root
+  });
+
+  assert!(!*even.get());
+  assert_eq!(root.text_content(), "odd");
+}
+
+#[derive(PartialEq)]
+struct Show {
+  value: PropContainer<i32>,
+}
+
+impl Component for Show {
+  fn render(&self) -> VNode {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+    h!(span).build(*self.value.value())
+  }
+}
+
+#[test]
+fn signal_props_are_compared_by_identity() {
+  let a = Signal::new(1);
+  let b = Signal::new(1);
+
+  let prop = |signal: &Signal<i32>| PropContainer::from(signal.clone());
+
+  assert_eq!(prop(&a), prop(&a));
+  assert_ne!(prop(&a), prop(&b));
+  assert_ne!(prop(&a), PropContainer::from(1));
+
+  let show = |signal: &Signal<i32>| {
+    Show {
+      value: prop(signal),
+    }
+    .memoized()
+    .build()
+  };
+  let root = render(show(&a));
+  assert_eq!(renders(), 1);
+
+  root.rerender(show(&a));
+  assert_eq!(renders(), 1);
+
+  root.rerender(show(&b));
+  assert_eq!(renders(), 2);
+}
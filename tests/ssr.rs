@@ -0,0 +1,103 @@
+#![cfg(native_runtime)]
+
+use js_sys::JsString;
+use wasm_bindgen::JsValue;
+use wasm_react::{
+  create_element, h, hooks::*, props::Props, ssr, Component, KeyType, Suspense,
+  VNode,
+};
+
+#[test]
+fn renders_attributes_with_non_ascii_names() {
+  let html = ssr::render_to_string(
+    h!(div)
+      .attr("oñx", &"1".into())
+      .attr("ño", &"2".into())
+      .build(()),
+  );
+
+  assert_eq!(html, r#"<div oñx="1" ño="2"></div>"#);
+}
+
+#[test]
+fn omits_event_handlers() {
+  let html = ssr::render_to_string(
+    h!(button)
+      .attr("onclick", &"alert(1)".into())
+      .attr("on", &"1".into())
+      .build("Click"),
+  );
+
+  assert_eq!(html, r#"<button on="1">Click</button>"#);
+}
+
+struct Greeting {
+  name: &'static str,
+}
+
+impl Component for Greeting {
+  fn render(&self) -> VNode {
+    let count = use_state(|| 1);
+    let count = *count.value();
+
+    // Effects don't run on the server
+    use_effect(|| -> () { panic!("effect has run") }, Deps::none());
+
+    h!(p).build(("Hello ", self.name, "! ", count))
+  }
+}
+
+#[test]
+fn renders_components_with_escaped_text() {
+  let html = ssr::render_to_string(Greeting { name: "<b>" }.build());
+
+  assert_eq!(html, "<p>Hello <!-- -->&lt;b&gt;<!-- -->! <!-- -->1</p>");
+}
+
+#[test]
+fn renders_suspense_boundaries() {
+  let html = ssr::render_to_string(
+    Suspense::new()
+      .fallback("Loading")
+      .build(h!(span).build("Loaded")),
+  );
+
+  assert_eq!(html, "<!--$--><span>Loaded</span><!--/$-->");
+}
+
+struct Online;
+
+impl Component for Online {
+  fn render(&self) -> VNode {
+    let is_online =
+      use_sync_external_store_with_server(|_| || (), || false, || true);
+    let is_online = *is_online.value();
+
+    h!(p).build(if is_online { "Online" } else { "Offline" })
+  }
+}
+
+#[test]
+fn renders_server_snapshots_of_external_stores() {
+  let html = ssr::render_to_string(Online.build());
+
+  assert_eq!(html, "<p>Online</p>");
+}
+
+#[test]
+fn keeps_js_backed_items() {
+  fn key_type<T: KeyType>() {}
+  fn into_js<T: Into<JsValue> + AsRef<JsValue>>() {}
+
+  let _: fn(&JsValue, &Props, VNode) -> VNode = create_element;
+  let _: fn(JsString) -> VNode = VNode::from;
+  key_type::<JsString>();
+  into_js::<VNode>();
+  into_js::<Props>();
+}
+
+#[test]
+#[should_panic(expected = "not supported by the native runtime")]
+fn panics_when_converting_native_nodes_into_js_values() {
+  let _ = JsValue::from(h!(div).build(()));
+}
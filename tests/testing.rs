@@ -0,0 +1,222 @@
+#![cfg(native_testing)]
+
+use std::{
+  cell::{Cell, RefCell},
+  rc::Rc,
+};
+use wasm_react::{
+  clones, create_context, h, hooks::*, testing::*, Callback, Component,
+  Context, ContextProvider, VNode, Void,
+};
+use web_sys::MouseEvent;
+
+thread_local! {
+  static RENDERS: Cell<u32> = const { Cell::new(0) };
+  static THEME: Context<&'static str> = create_context(Rc::new("light"));
+}
+
+fn renders() -> u32 {
+  RENDERS.with(|renders| renders.get())
+}
+
+fn click(root: &TestRoot, text: &str) {
+  root
+    .get(By::Text(text))
+    .fire("onClick", fake_event::<MouseEvent>());
+}
+
+#[derive(PartialEq)]
+struct Label {
+  text: &'static str,
+}
+
+impl Component for Label {
+  fn render(&self) -> VNode {
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    let theme = use_context(&THEME);
+
+    h!(span[#"label"]).build((self.text, " ", *theme))
+  }
+}
+
+struct Parent;
+
+impl Component for Parent {
+  fn render(&self) -> VNode {
+    let counter = use_state(|| 0);
+    let text = use_state(|| "a");
+    let theme = use_state(|| Rc::new("light"));
+    let counter_value = *counter.value();
+    let text_value = *text.value();
+    let theme_value = theme.value().clone();
+
+    h!(div).build((
+      h!(span[#"counter"]).build(counter_value),
+      h!(button)
+        .on_click(&Callback::new({
+          clones!(mut counter);
+          move |_| counter.set(|x| x + 1)
+        }))
+        .build("Increment"),
+      h!(button)
+        .on_click(&Callback::new({
+          clones!(mut text);
+          move |_| text.set(|_| "b")
+        }))
+        .build("Change text"),
+      h!(button)
+        .on_click(&Callback::new({
+          clones!(mut theme);
+          move |_| theme.set(|_| Rc::new("dark"))
+        }))
+        .build("Change theme"),
+      ContextProvider::from(&THEME)
+        .value(Some(theme_value))
+        .build(Label { text: text_value }.memoized().build()),
+    ))
+  }
+}
+
+#[test]
+fn memoized_components_skip_rendering_with_equal_props() {
+  let root = render(Parent.build());
+  let initial = renders();
+
+  assert_eq!(root.get(By::Id("label")).text_content(), "a light");
+
+  click(&root, "Increment");
+  assert_eq!(root.get(By::Id("counter")).text_content(), "1");
+  assert_eq!(renders(), initial);
+
+  click(&root, "Change text");
+  assert_eq!(root.get(By::Id("label")).text_content(), "b light");
+  assert_eq!(renders(), initial + 1);
+
+  click(&root, "Change theme");
+  assert_eq!(root.get(By::Id("label")).text_content(), "b dark");
+  assert_eq!(renders(), initial + 2);
+}
+
+thread_local! {
+  static EFFECTS: Cell<(u32, u32)> = const { Cell::new((0, 0)) };
+}
+
+fn effects() -> (u32, u32) {
+  EFFECTS.with(|effects| effects.get())
+}
+
+struct Effect {
+  dep: i32,
+}
+
+impl Component for Effect {
+  fn render(&self) -> VNode {
+    use_effect(
+      || {
+        EFFECTS.with(|effects| {
+          let (runs, cleanups) = effects.get();
+          effects.set((runs + 1, cleanups));
+        });
+
+        || {
+          EFFECTS.with(|effects| {
+            let (runs, cleanups) = effects.get();
+            effects.set((runs, cleanups + 1));
+          })
+        }
+      },
+      Deps::some(self.dep),
+    );
+
+    VNode::new()
+  }
+}
+
+#[test]
+fn effects_run_when_deps_change_and_clean_up_on_unmount() {
+  let root = render(Effect { dep: 0 }.build());
+  assert_eq!(effects(), (1, 0));
+
+  root.rerender(Effect { dep: 0 }.build());
+  assert_eq!(effects(), (1, 0));
+
+  root.rerender(Effect { dep: 1 }.build());
+  assert_eq!(effects(), (2, 1));
+
+  root.unmount();
+  assert_eq!(effects(), (2, 2));
+}
+
+struct Online;
+
+impl Component for Online {
+  fn render(&self) -> VNode {
+    let is_online =
+      use_sync_external_store_with_server(|_| || (), || false, || true);
+    let is_online = *is_online.value();
+
+    h!(p).build(if is_online { "Online" } else { "Offline" })
+  }
+}
+
+#[test]
+fn external_stores_use_client_snapshots() {
+  let root = render(Online.build());
+
+  assert_eq!(root.text_content(), "Offline");
+}
+
+thread_local! {
+  static STORE: Cell<u32> = const { Cell::new(0) };
+  static STORE_LISTENER: RefCell<Option<Callback<Void>>> =
+    const { RefCell::new(None) };
+  static STORE_RENDERS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn set_store(value: u32) {
+  STORE.with(|store| store.set(value));
+
+  let listener = STORE_LISTENER.with(|listener| listener.borrow().clone());
+  act(|| listener.expect("store is not subscribed to").call(Void));
+}
+
+struct Store;
+
+impl Component for Store {
+  fn render(&self) -> VNode {
+    STORE_RENDERS.with(|renders| renders.set(renders.get() + 1));
+
+    let value = use_sync_external_store(
+      |listener| {
+        STORE_LISTENER.with(|x| *x.borrow_mut() = Some(listener));
+        || STORE_LISTENER.with(|x| *x.borrow_mut() = None)
+      },
+      || STORE.with(|store| store.get()),
+    );
+    let value = *value.value();
+
+    h!(p).build(value)
+  }
+}
+
+#[test]
+fn external_stores_only_rerender_when_snapshots_change() {
+  let root = render(Store.build());
+  let renders = || STORE_RENDERS.with(|renders| renders.get());
+
+  assert_eq!(root.text_content(), "0");
+  assert_eq!(renders(), 1);
+
+  set_store(0);
+  assert_eq!(root.text_content(), "0");
+  assert_eq!(renders(), 1);
+
+  set_store(1);
+  assert_eq!(root.text_content(), "1");
+  assert_eq!(renders(), 2);
+
+  set_store(1);
+  assert_eq!(renders(), 2);
+
+  root.unmount();
+}
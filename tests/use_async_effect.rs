@@ -0,0 +1,94 @@
+#![cfg(all(native_testing, feature = "futures"))]
+
+mod common;
+
+use common::Gate;
+use std::cell::Cell;
+use wasm_react::{clones, h, hooks::*, testing::*, Component, VNode};
+
+thread_local! {
+  static CLEANUPS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn cleanups() -> u32 {
+  CLEANUPS.with(|cleanups| cleanups.get())
+}
+
+struct Fetch {
+  id: u32,
+  gate: Gate,
+}
+
+impl Component for Fetch {
+  fn render(&self) -> VNode {
+    let value = use_state(|| None);
+
+    use_async_effect(
+      {
+        clones!(self.id, self.gate, mut value);
+
+        move || async move {
+          gate.await;
+          value.set(|_| Some(id));
+
+          || CLEANUPS.with(|cleanups| cleanups.set(cleanups.get() + 1))
+        }
+      },
+      Deps::some(self.id),
+    );
+
+    let text = match *value.value() {
+      Some(id) => format!("value {id}"),
+      None => "loading".to_string(),
+    };
+
+    h!(span).build(text)
+  }
+}
+
+#[test]
+fn async_effects_update_state_once_resolved() {
+  let gate = Gate::default();
+  let root = render(
+    Fetch {
+      id: 1,
+      gate: gate.clone(),
+    }
+    .build(),
+  );
+
+  assert_eq!(root.text_content(), "loading");
+
+  act(|| gate.open());
+  assert_eq!(root.text_content(), "value 1");
+
+  root.unmount();
+  assert_eq!(cleanups(), 1);
+}
+
+#[test]
+fn async_effects_are_aborted_when_deps_change() {
+  let first = Gate::default();
+  let root = render(
+    Fetch {
+      id: 1,
+      gate: first.clone(),
+    }
+    .build(),
+  );
+
+  root.rerender(
+    Fetch {
+      id: 2,
+      gate: Gate::opened(),
+    }
+    .build(),
+  );
+  act(|| first.open());
+
+  assert_eq!(root.text_content(), "value 2");
+  assert_eq!(cleanups(), 0);
+
+  root.unmount();
+  assert_eq!(cleanups(), 1);
+}
@@ -0,0 +1,49 @@
+#![cfg(native_testing)]
+
+use std::cell::RefCell;
+use wasm_react::{h, hooks::*, testing::*, Callback, Component, VNode};
+
+thread_local! {
+  static CONNECTIONS: RefCell<Vec<Callback<(), &'static str>>> =
+    const { RefCell::new(Vec::new()) };
+}
+
+struct ChatRoom {
+  theme: &'static str,
+}
+
+impl Component for ChatRoom {
+  fn render(&self) -> VNode {
+    let on_connected = use_effect_event({
+      let theme = self.theme;
+      move |_| theme
+    });
+
+    use_effect(
+      move || {
+        CONNECTIONS
+          .with(|connections| connections.borrow_mut().push(on_connected))
+      },
+      Deps::none(),
+    );
+
+    h!(span).build(self.theme)
+  }
+}
+
+fn connections() -> Vec<Callback<(), &'static str>> {
+  CONNECTIONS.with(|connections| connections.borrow().clone())
+}
+
+#[test]
+fn effect_events_call_the_closure_of_the_latest_render() {
+  let root = render(ChatRoom { theme: "light" }.build());
+
+  assert_eq!(connections().len(), 1);
+  assert_eq!(connections()[0].call(()), "light");
+
+  root.rerender(ChatRoom { theme: "dark" }.build());
+
+  assert_eq!(connections().len(), 1);
+  assert_eq!(connections()[0].call(()), "dark");
+}
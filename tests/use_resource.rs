@@ -0,0 +1,102 @@
+#![cfg(all(native_testing, feature = "futures"))]
+
+mod common;
+
+use common::Gate;
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::JsValue;
+use wasm_react::{h, hooks::*, testing::*, Component, Suspense, VNode};
+
+thread_local! {
+  static FETCHES: Cell<u32> = const { Cell::new(0) };
+}
+
+fn fetches() -> u32 {
+  FETCHES.with(|fetches| fetches.get())
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key(u32);
+
+struct Resource {
+  id: u32,
+  gate: Gate,
+}
+
+impl Component for Resource {
+  fn render(&self) -> VNode {
+    let value: Rc<String> = use_resource(Key(self.id), |key| {
+      FETCHES.with(|fetches| fetches.set(fetches.get() + 1));
+      let gate = self.gate.clone();
+      let id = key.0;
+
+      async move {
+        gate.await;
+        Ok::<_, JsValue>(format!("value {id}"))
+      }
+    });
+
+    h!(span).build(&**value)
+  }
+}
+
+fn app(id: u32, gate: &Gate) -> VNode {
+  Suspense::new().fallback("Loading").build(
+    Resource {
+      id,
+      gate: gate.clone(),
+    }
+    .build(),
+  )
+}
+
+#[test]
+fn resources_suspend_until_act_drives_their_futures() {
+  let gate = Gate::default();
+  let root = render(app(1, &gate));
+
+  assert_eq!(root.text_content(), "Loading");
+
+  act(|| gate.open());
+  assert_eq!(root.text_content(), "value 1");
+}
+
+#[test]
+fn resources_are_evicted_when_unused() {
+  let gate = Gate::opened();
+
+  let root = render(h!(div).build((app(2, &gate), app(2, &gate))));
+  assert_eq!(root.text_content(), "value 2value 2");
+  assert_eq!(fetches(), 1);
+
+  // Still used by the other component
+  root.rerender(h!(div).build(app(2, &gate)));
+  root.rerender(h!(div).build((app(2, &gate), app(2, &gate))));
+  assert_eq!(fetches(), 1);
+
+  root.unmount();
+
+  let root = render(app(2, &gate));
+  assert_eq!(root.text_content(), "value 2");
+  assert_eq!(fetches(), 2);
+}
+
+#[test]
+fn resources_with_different_error_types_are_cached_separately() {
+  set_resource::<Key, String, ()>(Key(3), "preloaded".to_string());
+
+  let gate = Gate::opened();
+
+  let root = render(app(3, &gate));
+  assert_eq!(root.text_content(), "value 3");
+  assert_eq!(fetches(), 1);
+
+  set_resource::<Key, String, JsValue>(Key(3), "set".to_string());
+  root.flush_effects();
+  assert_eq!(root.text_content(), "set");
+
+  clear_resources::<Key, String, JsValue>();
+  root.flush_effects();
+  assert_eq!(root.text_content(), "value 3");
+  assert_eq!(fetches(), 2);
+}
@@ -0,0 +1,50 @@
+#![cfg(all(native_testing, feature = "futures"))]
+
+mod common;
+
+use common::Gate;
+use wasm_react::{clones, h, hooks::*, testing::*, Callback, Component, VNode};
+use web_sys::MouseEvent;
+
+struct Save {
+  gate: Gate,
+}
+
+impl Component for Save {
+  fn render(&self) -> VNode {
+    let saved = use_state(|| false);
+    let mut transition = use_transition();
+    let text = if *saved.value() { "Saved" } else { "Save" };
+
+    h!(button)
+      .on_click(&Callback::new({
+        clones!(self.gate, saved);
+
+        move |_: MouseEvent| {
+          transition.start_async({
+            clones!(gate, mut saved);
+
+            async move {
+              gate.await;
+              start_transition(move || saved.set(|_| true));
+            }
+          })
+        }
+      }))
+      .build(text)
+  }
+}
+
+#[test]
+fn async_transitions_run_their_futures() {
+  let gate = Gate::default();
+  let root = render(Save { gate: gate.clone() }.build());
+
+  root
+    .get(By::Text("Save"))
+    .fire("onClick", fake_event::<MouseEvent>());
+  assert_eq!(root.text_content(), "Save");
+
+  act(|| gate.open());
+  assert_eq!(root.text_content(), "Saved");
+}
@@ -0,0 +1,434 @@
+#![cfg(all(target_arch = "wasm32", feature = "react-dom"))]
+
+mod common;
+mod harness;
+
+#[cfg(feature = "futures")]
+use common::Gate;
+#[cfg(feature = "futures")]
+use harness::act_async;
+use harness::{flush_timers, react_major_version, TestContainer};
+use std::{cell::Cell, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use wasm_react::{clones, h, hooks::*, Callback, Component, VNode, Void};
+#[cfg(feature = "futures")]
+use wasm_react::{CaughtError, ErrorBoundary, Suspense};
+use web_sys::MouseEvent;
+
+thread_local! {
+  static EFFECT_COUNT: Cell<u32> = const { Cell::new(0) };
+  static CLEANUP_COUNT: Cell<u32> = const { Cell::new(0) };
+  static RENDER_COUNT: Cell<u32> = const { Cell::new(0) };
+  static FETCH_COUNT: Cell<u32> = const { Cell::new(0) };
+}
+
+fn increment(counter: &'static std::thread::LocalKey<Cell<u32>>) {
+  counter.with(|x| x.set(x.get() + 1));
+}
+
+fn get(counter: &'static std::thread::LocalKey<Cell<u32>>) -> u32 {
+  counter.with(|x| x.get())
+}
+
+fn reset() {
+  for counter in [&EFFECT_COUNT, &CLEANUP_COUNT, &RENDER_COUNT] {
+    counter.with(|x| x.set(0));
+  }
+}
+
+struct Counter;
+
+impl Component for Counter {
+  fn render(&self) -> VNode {
+    let counter = use_state(|| 0);
+    let value = *counter.value();
+
+    h!(div).build((
+      h!(span[#"value"]).build(value),
+      h!(button)
+        .on_click(&Callback::new({
+          clones!(mut counter);
+          move |_: MouseEvent| counter.set(|c| c + 1)
+        }))
+        .build("Increment"),
+    ))
+  }
+}
+
+#[wasm_bindgen_test]
+fn state_updates_rerender() {
+  let container = TestContainer::render(Counter.build());
+  assert_eq!(container.get("#value").text_content().unwrap(), "0");
+
+  container.click("button");
+  container.click("button");
+  assert_eq!(container.get("#value").text_content().unwrap(), "2");
+
+  container.unmount();
+}
+
+struct Effect {
+  dep: i32,
+}
+
+impl Component for Effect {
+  fn render(&self) -> VNode {
+    use_effect(
+      || {
+        increment(&EFFECT_COUNT);
+        || increment(&CLEANUP_COUNT)
+      },
+      Deps::some(self.dep),
+    );
+
+    VNode::new()
+  }
+}
+
+#[wasm_bindgen_test]
+fn effects_run_when_deps_change() {
+  reset();
+
+  let container = TestContainer::render(Effect { dep: 0 }.build());
+  assert_eq!((get(&EFFECT_COUNT), get(&CLEANUP_COUNT)), (1, 0));
+
+  container.rerender(Effect { dep: 0 }.build());
+  assert_eq!((get(&EFFECT_COUNT), get(&CLEANUP_COUNT)), (1, 0));
+
+  container.rerender(Effect { dep: 1 }.build());
+  assert_eq!((get(&EFFECT_COUNT), get(&CLEANUP_COUNT)), (2, 1));
+
+  container.unmount();
+  assert_eq!((get(&EFFECT_COUNT), get(&CLEANUP_COUNT)), (2, 2));
+}
+
+#[derive(PartialEq)]
+struct Memo {
+  value: i32,
+}
+
+impl Component for Memo {
+  fn render(&self) -> VNode {
+    increment(&RENDER_COUNT);
+    h!(span).build(self.value)
+  }
+}
+
+#[wasm_bindgen_test]
+fn memo_components_skip_equal_props() {
+  reset();
+
+  let container = TestContainer::render(Memo { value: 0 }.memoized().build());
+  assert_eq!(get(&RENDER_COUNT), 1);
+
+  container.rerender(Memo { value: 0 }.memoized().build());
+  assert_eq!(get(&RENDER_COUNT), 1);
+
+  container.rerender(Memo { value: 1 }.memoized().build());
+  assert_eq!(get(&RENDER_COUNT), 2);
+  assert_eq!(container.text_content(), "1");
+
+  container.unmount();
+}
+
+struct TmpRefs {
+  tracker: Rc<()>,
+}
+
+impl Component for TmpRefs {
+  fn render(&self) -> VNode {
+    let tracker = self.tracker.clone();
+
+    // Each render passes a new callback to React as temporary ref
+    h!(button)
+      .on_click(&Callback::new(move |_: MouseEvent| {
+        let _ = &tracker;
+      }))
+      .build(())
+  }
+}
+
+#[wasm_bindgen_test]
+async fn tmp_refs_are_freed() {
+  let tracker = Rc::new(());
+  let container = TestContainer::render(
+    TmpRefs {
+      tracker: tracker.clone(),
+    }
+    .build(),
+  );
+
+  for _ in 0..3 {
+    container.rerender(
+      TmpRefs {
+        tracker: tracker.clone(),
+      }
+      .build(),
+    );
+  }
+
+  flush_timers().await;
+
+  // Only the callback of the latest render and the component props are alive
+  assert_eq!(Rc::strong_count(&tracker), 3);
+
+  container.unmount();
+  flush_timers().await;
+  assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[wasm_bindgen_test]
+fn forwarded_refs_are_added_to_props() {
+  use js_sys::{Function, Object, Reflect};
+  use wasm_react::props::with_forwarded_ref;
+
+  let has_ref = |props: &JsValue| {
+    Reflect::has(props.unchecked_ref(), &"ref".into()).unwrap()
+  };
+
+  // React 18 passes the legacy context object to plain function components
+  let props = with_forwarded_ref(Object::new().into(), Object::new().into());
+  assert!(!has_ref(&props));
+
+  let props = with_forwarded_ref(Object::new().into(), JsValue::NULL);
+  assert!(!has_ref(&props));
+
+  let ref_object = Object::new();
+  Reflect::set(&ref_object, &"current".into(), &JsValue::NULL).unwrap();
+  let props = with_forwarded_ref(Object::new().into(), ref_object.into());
+  assert!(has_ref(&props));
+
+  let props =
+    with_forwarded_ref(Object::new().into(), Function::new_no_args("").into());
+  assert!(has_ref(&props));
+}
+
+struct Answer {
+  handle: JsRefContainer<ImperativeHandle>,
+}
+
+impl Component for Answer {
+  fn render(&self) -> VNode {
+    use_imperative_handle(
+      &self.handle,
+      || {
+        ImperativeHandleBuilder::new()
+          .method("answer", &Callback::new(|_: Void| 42u32))
+      },
+      Deps::none(),
+    );
+
+    VNode::new()
+  }
+}
+
+struct Question;
+
+impl Component for Question {
+  fn render(&self) -> VNode {
+    let handle = use_js_ref::<ImperativeHandle>(None);
+    let answer = use_state(|| None);
+    let answer_value = *answer.value();
+
+    h!(div).build((
+      h!(button)
+        .on_click(&Callback::new({
+          clones!(handle, mut answer);
+
+          move |_: MouseEvent| {
+            let value = handle
+              .current()
+              .and_then(|handle| handle.method::<Void, u32>("answer"))
+              .map(|method| method.call(Void));
+
+            answer.set(|_| value);
+          }
+        }))
+        .build("Ask"),
+      h!(span[#"answer"]).build(answer_value),
+      Answer { handle }.build(),
+    ))
+  }
+}
+
+#[wasm_bindgen_test]
+fn parents_call_methods_of_imperative_handles() {
+  let container = TestContainer::render(Question.build());
+  assert_eq!(container.get("#answer").text_content().unwrap(), "");
+
+  container.click("button");
+  assert_eq!(container.get("#answer").text_content().unwrap(), "42");
+
+  container.unmount();
+}
+
+#[wasm_bindgen_test]
+fn integer_return_values_are_checked() {
+  use wasm_react::FromJsReturn;
+
+  assert_eq!(u8::from_js_return(255.into()).ok(), Some(255));
+  assert!(u8::from_js_return(256.into()).is_err());
+  assert!(u32::from_js_return((-1).into()).is_err());
+  assert!(i32::from_js_return(1.5.into()).is_err());
+  assert!(i64::from_js_return(f64::NAN.into()).is_err());
+  assert_eq!(f64::from_js_return(1.5.into()).ok(), Some(1.5));
+}
+
+struct Cart;
+
+impl Component for Cart {
+  fn render(&self) -> VNode {
+    let cart = use_action_state(
+      |items: &Vec<&'static str>, item: &'static str| {
+        let mut items = items.clone();
+        items.push(item);
+        items
+      },
+      Vec::new,
+    );
+    let len = cart.state().len();
+
+    h!(button)
+      .on_click(&cart.dispatch().to_callback().premap(|_| "Apple"))
+      .build(len)
+  }
+}
+
+#[wasm_bindgen_test]
+fn action_state_updates_state() {
+  if react_major_version() < 19 {
+    return;
+  }
+
+  let container = TestContainer::render(Cart.build());
+  assert_eq!(container.text_content(), "0");
+
+  container.click("button");
+  container.click("button");
+  assert_eq!(container.text_content(), "2");
+
+  container.unmount();
+}
+
+#[cfg(feature = "futures")]
+struct Sender {
+  gate: Gate,
+}
+
+#[cfg(feature = "futures")]
+impl Component for Sender {
+  fn render(&self) -> VNode {
+    let sending = use_optimistic(&0, |sending, count: u32| sending + count);
+    let sent = use_async_action_state(
+      {
+        clones!(self.gate, sending);
+
+        move |sent: &u32, count: u32| {
+          sending.add(count);
+
+          clones!(gate);
+          let sent = *sent;
+
+          async move {
+            gate.await;
+            sent + count
+          }
+        }
+      },
+      || 0,
+    );
+    let sent_value = *sent.state();
+
+    h!(button)
+      .on_click(&sent.dispatch().to_callback().premap(|_| 1))
+      .build((
+        *sending.value(),
+        "/",
+        sent_value,
+        if sent.is_pending() { "…" } else { "" },
+      ))
+  }
+}
+
+#[cfg(feature = "futures")]
+#[wasm_bindgen_test]
+async fn optimistic_updates_are_shown_while_pending() {
+  if react_major_version() < 19 {
+    return;
+  }
+
+  let gate = Gate::default();
+  let container = TestContainer::render(Sender { gate: gate.clone() }.build());
+  assert_eq!(container.text_content(), "0/0");
+
+  container.click("button");
+  assert_eq!(container.text_content(), "1/0…");
+
+  act_async(async move {
+    gate.open();
+    flush_timers().await;
+  })
+  .await;
+  assert_eq!(container.text_content(), "0/1");
+
+  container.unmount();
+}
+
+#[cfg(feature = "futures")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FlakyKey;
+
+#[cfg(feature = "futures")]
+struct Flaky;
+
+#[cfg(feature = "futures")]
+impl Component for Flaky {
+  fn render(&self) -> VNode {
+    let value: Rc<String> = use_resource(FlakyKey, |_| {
+      increment(&FETCH_COUNT);
+      let fails = get(&FETCH_COUNT) == 1;
+
+      async move {
+        if fails {
+          Err(JsValue::from("Failed"))
+        } else {
+          Ok("Loaded".to_string())
+        }
+      }
+    });
+
+    h!(span).build(&**value)
+  }
+}
+
+#[cfg(feature = "futures")]
+struct Retry;
+
+#[cfg(feature = "futures")]
+impl Component for Retry {
+  fn render(&self) -> VNode {
+    ErrorBoundary::new()
+      .fallback(&Callback::new(|error: CaughtError| {
+        h!(button)
+          .on_click(&error.reset().unwrap().premap(|_| Void))
+          .build("Retry")
+      }))
+      .build(Suspense::new().fallback("Loading").build(Flaky.build()))
+  }
+}
+
+#[cfg(feature = "futures")]
+#[wasm_bindgen_test]
+async fn resetting_error_boundaries_refetches_failed_resources() {
+  let container = TestContainer::render(Retry.build());
+  act_async(flush_timers()).await;
+  assert_eq!(container.text_content(), "Retry");
+
+  container.click("button");
+  act_async(flush_timers()).await;
+  assert_eq!(container.text_content(), "Loaded");
+  assert_eq!(get(&FETCH_COUNT), 2);
+
+  container.unmount();
+}